@@ -0,0 +1,116 @@
+use ndc_client::models::{Argument, QueryRequest};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::configuration;
+use crate::error::ServerError;
+use crate::tables::SupportedTable;
+
+// get's an argument value from arguments and variables provided in the request
+// the borrowing can be improved
+pub fn get_argument_value<'a>(
+    arguments: &'a HashMap<String, Argument>,
+    variables: &'a [HashMap<String, serde_json::Value>],
+    key: &str,
+) -> Option<&'a serde_json::Value> {
+    let argument = arguments.get(key);
+
+    match argument {
+        Some(arg) => match arg {
+            Argument::Variable { name } => variables
+                .iter()
+                .find(|p| p.get(name).is_some())
+                .and_then(|vars| vars.get(name)),
+            Argument::Literal { value } => Some(value),
+        },
+        None => None,
+    }
+}
+
+// gets a boolean argument from the request, defaulting to false when it is not provided
+pub fn get_bool_argument(
+    arguments: &HashMap<String, Argument>,
+    variables: &[HashMap<String, serde_json::Value>],
+    key: &str,
+) -> bool {
+    matches!(
+        get_argument_value(arguments, variables, key),
+        Some(serde_json::Value::Bool(true))
+    )
+}
+
+// gets the URL of the database the request is run against: its database_url argument, or
+// DEFAULT_DB_URL when it has none
+pub fn get_database_url(
+    arguments: &HashMap<String, Argument>,
+    variables: &[HashMap<String, serde_json::Value>],
+) -> Result<String, ServerError> {
+    match get_argument_value(arguments, variables, "database_url") {
+        Some(serde_json::Value::String(database_url)) => Ok(database_url.clone()),
+        Some(_) => Err(ServerError::BadRequest("invalid db url".into())),
+        None => configuration::get_default_db_url()
+            .ok_or_else(|| ServerError::BadRequest("no db url provided".into())),
+    }
+}
+
+// the deepest the dependencies table walks transitive dependents
+pub const MAX_DEPENDENCY_DEPTH: u64 = 10;
+
+// arguments of a table that change the catalog query its rows are read from,
+// as opposed to the ones that only filter the rows
+#[derive(Debug, Clone)]
+pub struct TableArguments {
+    // how many levels of transitive dependents the dependencies table walks
+    pub max_depth: u64,
+    // whether the query is run against every database on the server, in which case the rows
+    // are ordered the way they are merged
+    pub all_databases: bool,
+    // the URL of the database, which picks the lint configuration of its source
+    pub database_url: Option<String>,
+}
+
+impl Default for TableArguments {
+    fn default() -> Self {
+        TableArguments {
+            max_depth: 1,
+            all_databases: false,
+            database_url: None,
+        }
+    }
+}
+
+impl TableArguments {
+    pub fn from_request(request: &QueryRequest) -> Result<Self, ServerError> {
+        let variables = request.variables.clone().unwrap_or_default();
+        let mut table_arguments = TableArguments {
+            database_url: get_database_url(&request.arguments, &variables).ok(),
+            ..TableArguments::default()
+        };
+        if get_bool_argument(&request.arguments, &variables, "all_databases") {
+            if let Ok(table) = SupportedTable::from_str(&request.table) {
+                if table.is_server_wide() {
+                    return Err(ServerError::BadRequest(format!(
+                        "{} are shared by every database on the server, all_databases is not supported",
+                        request.table
+                    )));
+                }
+            }
+            table_arguments.all_databases = true;
+        }
+        match get_argument_value(&request.arguments, &variables, "max_depth") {
+            Some(serde_json::Value::Null) | None => {}
+            Some(value) => match value.as_u64() {
+                Some(depth) if (1..=MAX_DEPENDENCY_DEPTH).contains(&depth) => {
+                    table_arguments.max_depth = depth
+                }
+                _ => {
+                    return Err(ServerError::BadRequest(format!(
+                        "max_depth should be an integer between 1 and {}",
+                        MAX_DEPENDENCY_DEPTH
+                    )))
+                }
+            },
+        }
+        Ok(table_arguments)
+    }
+}
@@ -0,0 +1,2 @@
+// the field of the row a command, like schema_diff, snapshot or er_diagram, returns its result in
+pub const COMMAND_VALUE_FIELD: &str = "__value";
@@ -1,8 +1,18 @@
+use std::collections::HashMap;
 use std::env;
 
 pub struct Configuration {
     pub port: u32,
-    pub default_database_url: Option<String>
+    pub default_database_url: Option<String>,
+}
+
+// where the tables and columns tables are read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatalogBackend {
+    // the information_schema views, which only show the objects the role has privileges on
+    InformationSchema,
+    // pg_class, pg_attribute and pg_namespace; faster on large databases, and shows every object
+    PgCatalog,
 }
 
 fn get_port() -> u32 {
@@ -17,6 +27,66 @@ pub fn get_default_db_url() -> Option<String> {
     env::var("DEFAULT_DB_URL").map(|url|Some(url)).unwrap_or(None)
 }
 
+// CATALOG_BACKEND is either information_schema (the default) or pg_catalog
+pub fn get_catalog_backend() -> CatalogBackend {
+    match env::var("CATALOG_BACKEND").as_deref() {
+        Ok("pg_catalog") => CatalogBackend::PgCatalog,
+        _ => CatalogBackend::InformationSchema,
+    }
+}
+
+// how many databases a query that fans out to every database runs against at a time
+pub fn get_fan_out_concurrency() -> usize {
+    env::var("FAN_OUT_CONCURRENCY")
+        .ok()
+        .and_then(|concurrency| concurrency.parse().ok())
+        .filter(|concurrency| *concurrency > 0)
+        .unwrap_or(4)
+}
+
+// REDACT_QUERY_TEXT=true replaces the literals in the query texts of sessions and blocking_chains
+pub fn get_redact_query_text() -> bool {
+    matches!(env::var("REDACT_QUERY_TEXT").as_deref(), Ok("true"))
+}
+
+// LINT_CONFIG is the path of a JSON file that disables or changes the severity of lint rules
+pub fn get_lint_config_path() -> Option<String> {
+    env::var("LINT_CONFIG").ok()
+}
+
+// SOURCES names the databases that commands like schema_diff can refer to by name, as a
+// whitespace separated list of name=url pairs, since a URL cannot contain a space:
+// SOURCES="staging=postgres://... production=postgres://..."
+pub fn get_sources() -> HashMap<String, String> {
+    env::var("SOURCES")
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter_map(|source| source.split_once('='))
+        .map(|(name, url)| (name.trim().to_string(), url.trim().to_string()))
+        .collect()
+}
+
+// ALLOW_SOURCE_URLS=true lets commands like schema_diff take a database URL instead of the name
+// of one of the SOURCES
+pub fn get_allow_source_urls() -> bool {
+    matches!(env::var("ALLOW_SOURCE_URLS").as_deref(), Ok("true"))
+}
+
+// DATA_SOURCE_URL is the URL the engine reaches the connector at, which generate-metadata writes
+// into the metadata
+pub fn get_data_source_url() -> Option<String> {
+    env::var("DATA_SOURCE_URL").ok()
+}
+
+// SNAPSHOT_DIR is the directory of the snapshot files that snapshot: URLs name; without it,
+// snapshots cannot be queried
+pub fn get_snapshot_dir() -> Option<String> {
+    env::var("SNAPSHOT_DIR").ok()
+}
+
 pub fn get_configuration() -> Configuration {
-    Configuration { port: get_port(), default_database_url: get_default_db_url() }
+    Configuration {
+        port: get_port(),
+        default_database_url: get_default_db_url(),
+    }
 }
@@ -0,0 +1,958 @@
+use ndc_client::models::{self, QueryRequest};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::er_diagram::{get_foreign_keys_request, ErForeignKey};
+use crate::error::ServerError;
+use crate::rows::get_response_rows;
+use crate::tables::{COLUMNS, FOREIGN_KEYS, INDEXES, TABLES};
+
+// the formats a data dictionary can be written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataDictionaryFormat {
+    Markdown,
+    Html,
+}
+
+impl DataDictionaryFormat {
+    pub fn get_extension(&self) -> &'static str {
+        match self {
+            DataDictionaryFormat::Markdown => "md",
+            DataDictionaryFormat::Html => "html",
+        }
+    }
+}
+
+impl FromStr for DataDictionaryFormat {
+    type Err = ServerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" => Ok(DataDictionaryFormat::Markdown),
+            "html" => Ok(DataDictionaryFormat::Html),
+            _ => Err(ServerError::BadRequest(format!(
+                "unknown format {}; expected markdown or html",
+                s
+            ))),
+        }
+    }
+}
+
+// the file name of the page that lists the schemas, without its extension
+const INDEX_PAGE: &str = "index";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DictionaryTable {
+    pub table_schema: String,
+    pub table_name: String,
+    pub table_type: String,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DictionaryColumn {
+    pub table_schema: String,
+    pub table_name: String,
+    pub column_name: String,
+    pub data_type: String,
+    pub is_nullable: bool,
+    pub column_default: Option<String>,
+    pub ordinal_position: i64,
+    pub character_maximum_length: Option<i64>,
+    pub numeric_precision: Option<i64>,
+    pub numeric_scale: Option<i64>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DictionaryIndex {
+    pub table_schema: String,
+    pub table_name: String,
+    pub index_name: String,
+    pub is_primary: bool,
+    pub is_unique: bool,
+    pub key_columns: Vec<String>,
+    pub definition: String,
+}
+
+// the catalog a data dictionary is written from, as returned by the tables, columns, indexes
+// and foreign_keys tables
+#[derive(Debug, Clone, Default)]
+pub struct DataDictionary {
+    pub tables: Vec<DictionaryTable>,
+    pub columns: Vec<DictionaryColumn>,
+    pub indexes: Vec<DictionaryIndex>,
+    pub foreign_keys: Vec<ErForeignKey>,
+}
+
+// gets the requests that read the catalog of a data dictionary, by the table they query.
+// Partitions are documented as part of their partitioned table.
+pub fn get_data_dictionary_requests() -> Vec<QueryRequest> {
+    let mut tables_request = get_request(
+        TABLES,
+        &["table_schema", "table_name", "table_type", "comment"],
+    );
+    tables_request.arguments.insert(
+        "exclude_partitions".into(),
+        models::Argument::Literal {
+            value: Value::Bool(true),
+        },
+    );
+    vec![
+        tables_request,
+        get_request(
+            COLUMNS,
+            &[
+                "table_schema",
+                "table_name",
+                "column_name",
+                "data_type",
+                "is_nullable",
+                "column_default",
+                "ordinal_position",
+                "character_maximum_length",
+                "numeric_precision",
+                "numeric_scale",
+                "comment",
+            ],
+        ),
+        get_request(
+            INDEXES,
+            &[
+                "table_schema",
+                "table_name",
+                "index_name",
+                "is_primary",
+                "is_unique",
+                "key_columns",
+                "definition",
+            ],
+        ),
+        get_foreign_keys_request(),
+    ]
+}
+
+fn get_request(table: &str, columns: &[&str]) -> QueryRequest {
+    QueryRequest {
+        table: table.into(),
+        query: models::Query {
+            aggregates: None,
+            fields: Some(
+                columns
+                    .iter()
+                    .map(|column| {
+                        (
+                            column.to_string(),
+                            models::Field::Column {
+                                column: column.to_string(),
+                                arguments: HashMap::new(),
+                            },
+                        )
+                    })
+                    .collect(),
+            ),
+            limit: None,
+            offset: None,
+            order_by: None,
+            predicate: None,
+        },
+        arguments: HashMap::new(),
+        table_relationships: HashMap::new(),
+        variables: None,
+    }
+}
+
+impl DataDictionary {
+    // adds the rows of the response to one of the data dictionary requests
+    pub fn add_response(&mut self, table: &str, response: &Value) -> Result<(), ServerError> {
+        match table {
+            TABLES => self.tables.extend(get_rows(response)?),
+            COLUMNS => self.columns.extend(get_rows(response)?),
+            INDEXES => self.indexes.extend(get_rows(response)?),
+            FOREIGN_KEYS => self.foreign_keys.extend(get_rows(response)?),
+            _ => return Err(ServerError::Internal(format!("unexpected table {}", table))),
+        }
+        Ok(())
+    }
+
+    // gets the pages of the data dictionary by file name: an index of the schemas, and a page
+    // per schema with a section per table
+    pub fn get_pages(&self, format: DataDictionaryFormat) -> Vec<(String, String)> {
+        let mut schemas: BTreeMap<&str, Vec<&DictionaryTable>> = BTreeMap::new();
+        for table in &self.tables {
+            schemas.entry(&table.table_schema).or_default().push(table);
+        }
+        for tables in schemas.values_mut() {
+            tables.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+        }
+
+        let renderer: &dyn PageRenderer = match format {
+            DataDictionaryFormat::Markdown => &MarkdownRenderer,
+            DataDictionaryFormat::Html => &HtmlRenderer,
+        };
+        let extension = format.get_extension();
+        let file_names: BTreeMap<&str, String> = get_page_names(schemas.keys().copied())
+            .into_iter()
+            .map(|(schema, name)| (schema, format!("{}.{}", name, extension)))
+            .collect();
+
+        let mut index = renderer.get_heading(1, "Data dictionary", None);
+        index.push_str(
+            &renderer.get_list(
+                schemas
+                    .iter()
+                    .map(|(schema, tables)| {
+                        format!(
+                            "{} ({} tables)",
+                            renderer.get_link(schema, &file_names[schema]),
+                            tables.len()
+                        )
+                    })
+                    .collect(),
+            ),
+        );
+        let mut pages = vec![(
+            format!("{}.{}", INDEX_PAGE, extension),
+            renderer.get_page("Data dictionary", &index),
+        )];
+
+        for (schema, tables) in &schemas {
+            let mut body = renderer.get_heading(1, &format!("Schema {}", schema), None);
+            body.push_str(
+                &renderer.get_list(
+                    tables
+                        .iter()
+                        .map(|t| {
+                            renderer
+                                .get_link(&t.table_name, &format!("#{}", get_anchor(&t.table_name)))
+                        })
+                        .collect(),
+                ),
+            );
+            for table in tables {
+                body.push_str(&self.get_table_section(renderer, table, &file_names));
+            }
+            pages.push((
+                file_names[schema].clone(),
+                renderer.get_page(&format!("Schema {}", schema), &body),
+            ));
+        }
+        pages
+    }
+
+    fn get_table_section(
+        &self,
+        renderer: &dyn PageRenderer,
+        table: &DictionaryTable,
+        file_names: &BTreeMap<&str, String>,
+    ) -> String {
+        let is_table =
+            |schema: &str, name: &str| schema == table.table_schema && name == table.table_name;
+        let mut columns: Vec<&DictionaryColumn> = self
+            .columns
+            .iter()
+            .filter(|c| is_table(&c.table_schema, &c.table_name))
+            .collect();
+        columns.sort_by_key(|c| c.ordinal_position);
+        let mut indexes: Vec<&DictionaryIndex> = self
+            .indexes
+            .iter()
+            .filter(|i| is_table(&i.table_schema, &i.table_name))
+            .collect();
+        indexes.sort_by(|a, b| a.index_name.cmp(&b.index_name));
+        let mut foreign_keys: Vec<&ErForeignKey> = self
+            .foreign_keys
+            .iter()
+            .filter(|fk| is_table(&fk.schema_from, &fk.table_from))
+            .collect();
+        foreign_keys.sort_by(|a, b| a.fkey_name.cmp(&b.fkey_name));
+        let mut referenced_by: Vec<&ErForeignKey> = self
+            .foreign_keys
+            .iter()
+            .filter(|fk| is_table(&fk.schema_to, &fk.table_to))
+            .collect();
+        referenced_by.sort_by(|a, b| {
+            (&a.schema_from, &a.table_from, &a.fkey_name).cmp(&(
+                &b.schema_from,
+                &b.table_from,
+                &b.fkey_name,
+            ))
+        });
+
+        let mut section =
+            renderer.get_heading(2, &table.table_name, Some(&get_anchor(&table.table_name)));
+        if let Some(comment) = &table.comment {
+            section.push_str(&renderer.get_paragraph(&renderer.get_text(comment)));
+        }
+        section.push_str(
+            &renderer.get_paragraph(&format!("Type: {}", renderer.get_text(&table.table_type))),
+        );
+
+        section.push_str(&renderer.get_heading(3, "Columns", None));
+        section.push_str(
+            &renderer.get_table(
+                &["Column", "Type", "Nullable", "Default", "Key", "Comment"],
+                columns
+                    .iter()
+                    .map(|c| {
+                        vec![
+                            renderer.get_code(&c.column_name),
+                            renderer.get_code(&get_column_type(c)),
+                            if c.is_nullable { "yes" } else { "no" }.into(),
+                            c.column_default
+                                .as_ref()
+                                .map(|d| renderer.get_code(d))
+                                .unwrap_or_default(),
+                            get_key_markers(c, &indexes, &foreign_keys).join(", "),
+                            c.comment
+                                .as_ref()
+                                .map(|comment| renderer.get_text(comment))
+                                .unwrap_or_default(),
+                        ]
+                    })
+                    .collect(),
+            ),
+        );
+
+        if !indexes.is_empty() {
+            section.push_str(&renderer.get_heading(3, "Indexes", None));
+            section.push_str(
+                &renderer.get_table(
+                    &["Index", "Columns", "Unique", "Definition"],
+                    indexes
+                        .iter()
+                        .map(|i| {
+                            vec![
+                                renderer.get_code(&i.index_name),
+                                renderer.get_text(&i.key_columns.join(", ")),
+                                if i.is_primary {
+                                    "primary key"
+                                } else if i.is_unique {
+                                    "yes"
+                                } else {
+                                    "no"
+                                }
+                                .into(),
+                                renderer.get_code(&i.definition),
+                            ]
+                        })
+                        .collect(),
+                ),
+            );
+        }
+
+        if !foreign_keys.is_empty() {
+            section.push_str(&renderer.get_heading(3, "Foreign keys", None));
+            section.push_str(
+                &renderer.get_table(
+                    &["Foreign key", "Columns", "References"],
+                    foreign_keys
+                        .iter()
+                        .map(|fk| {
+                            vec![
+                                renderer.get_code(&fk.fkey_name),
+                                renderer.get_text(&get_column_mapping(fk)),
+                                get_table_link(renderer, file_names, &fk.schema_to, &fk.table_to),
+                            ]
+                        })
+                        .collect(),
+                ),
+            );
+        }
+
+        if !referenced_by.is_empty() {
+            section.push_str(&renderer.get_heading(3, "Referenced by", None));
+            section.push_str(
+                &renderer.get_table(
+                    &["Table", "Foreign key", "Columns"],
+                    referenced_by
+                        .iter()
+                        .map(|fk| {
+                            vec![
+                                get_table_link(
+                                    renderer,
+                                    file_names,
+                                    &fk.schema_from,
+                                    &fk.table_from,
+                                ),
+                                renderer.get_code(&fk.fkey_name),
+                                renderer.get_text(&get_column_mapping(fk)),
+                            ]
+                        })
+                        .collect(),
+                ),
+            );
+        }
+        section
+    }
+}
+
+fn get_rows<T: DeserializeOwned>(response: &Value) -> Result<Vec<T>, ServerError> {
+    get_response_rows(response)?
+        .into_iter()
+        .map(|row| {
+            serde_json::from_value(Value::Object(row))
+                .map_err(|err| ServerError::Internal(err.to_string()))
+        })
+        .collect()
+}
+
+// the type of a column, with the length of character types and the precision of numeric ones
+fn get_column_type(column: &DictionaryColumn) -> String {
+    match (
+        column.character_maximum_length,
+        column.numeric_precision,
+        column.numeric_scale,
+    ) {
+        (Some(length), _, _) => format!("{}({})", column.data_type, length),
+        (_, Some(precision), Some(scale)) if column.data_type == "numeric" => {
+            format!("{}({}, {})", column.data_type, precision, scale)
+        }
+        _ => column.data_type.clone(),
+    }
+}
+
+// PK, UK and FK for the columns that are part of the primary key, a unique index or a foreign
+// key of the table
+fn get_key_markers(
+    column: &DictionaryColumn,
+    indexes: &[&DictionaryIndex],
+    foreign_keys: &[&ErForeignKey],
+) -> Vec<&'static str> {
+    let is_key_column = |i: &&&DictionaryIndex| i.key_columns.contains(&column.column_name);
+    [
+        indexes.iter().filter(is_key_column).any(|i| i.is_primary),
+        indexes
+            .iter()
+            .filter(is_key_column)
+            .any(|i| i.is_unique && !i.is_primary),
+        foreign_keys.iter().any(|fk| {
+            fk.column_mapping
+                .iter()
+                .any(|m| m.from == column.column_name)
+        }),
+    ]
+    .into_iter()
+    .zip(["PK", "UK", "FK"])
+    .filter(|(is_key, _)| *is_key)
+    .map(|(_, marker)| marker)
+    .collect()
+}
+
+fn get_column_mapping(foreign_key: &ErForeignKey) -> String {
+    foreign_key
+        .column_mapping
+        .iter()
+        .map(|m| format!("{} → {}", m.from, m.to))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// links to the section of a table on the page of its schema. A foreign key can reference a table
+// of a schema that has no page, like one of partitions only, which is named without a link.
+fn get_table_link(
+    renderer: &dyn PageRenderer,
+    file_names: &BTreeMap<&str, String>,
+    schema: &str,
+    table: &str,
+) -> String {
+    let name = format!("{}.{}", schema, table);
+    match file_names.get(schema) {
+        Some(file_name) => {
+            renderer.get_link(&name, &format!("{}#{}", file_name, get_anchor(table)))
+        }
+        None => renderer.get_text(&name),
+    }
+}
+
+// the file names of the pages of the schemas, without their extension. A name that is already
+// taken, by the index or by a schema that only differs in case (which file systems that ignore
+// case would write to the same file), gets a ~2, ~3... suffix; ~ is never part of an encoded
+// name, so the suffixed names cannot collide with the others.
+fn get_page_names<'a>(schemas: impl Iterator<Item = &'a str>) -> BTreeMap<&'a str, String> {
+    let mut taken = HashSet::from([INDEX_PAGE.to_string()]);
+    let mut names = BTreeMap::new();
+    for schema in schemas {
+        let encoded = get_page_name(schema);
+        let mut name = encoded.clone();
+        let mut suffix = 1;
+        while !taken.insert(name.to_lowercase()) {
+            suffix += 1;
+            name = format!("{}~{}", encoded, suffix);
+        }
+        names.insert(schema, name);
+    }
+    names
+}
+
+// the file name of the page of a schema, without its extension: the ASCII letters, digits and _
+// of its name, with any other byte written as -xx in hex, so that a name like ../x or a/b stays
+// a single file of the output directory
+fn get_page_name(schema: &str) -> String {
+    let mut name = String::new();
+    for byte in schema.bytes() {
+        if byte.is_ascii_alphanumeric() || byte == b'_' {
+            name.push(byte as char);
+        } else {
+            let _ = write!(name, "-{:02x}", byte);
+        }
+    }
+    name
+}
+
+// the anchor of the section of a table: its name, with the characters that need escaping in a
+// URL replaced by dashes
+fn get_anchor(table_name: &str) -> String {
+    table_name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+// writes the elements of a page in one of the formats. get_text and get_code escape their
+// text; the other methods take text that is already escaped.
+trait PageRenderer {
+    fn get_page(&self, title: &str, body: &str) -> String;
+    fn get_heading(&self, level: usize, text: &str, anchor: Option<&str>) -> String;
+    fn get_paragraph(&self, text: &str) -> String;
+    fn get_list(&self, items: Vec<String>) -> String;
+    fn get_table(&self, headers: &[&str], rows: Vec<Vec<String>>) -> String;
+    fn get_link(&self, text: &str, href: &str) -> String;
+    fn get_text(&self, text: &str) -> String;
+    fn get_code(&self, text: &str) -> String;
+}
+
+struct MarkdownRenderer;
+
+impl PageRenderer for MarkdownRenderer {
+    fn get_page(&self, _title: &str, body: &str) -> String {
+        body.to_string()
+    }
+
+    fn get_heading(&self, level: usize, text: &str, anchor: Option<&str>) -> String {
+        let mut heading = String::new();
+        if let Some(anchor) = anchor {
+            let _ = writeln!(heading, "<a id=\"{}\"></a>\n", anchor);
+        }
+        let _ = writeln!(heading, "{} {}\n", "#".repeat(level), self.get_text(text));
+        heading
+    }
+
+    fn get_paragraph(&self, text: &str) -> String {
+        format!("{}\n\n", text)
+    }
+
+    fn get_list(&self, items: Vec<String>) -> String {
+        let mut list: String = items.iter().map(|item| format!("- {}\n", item)).collect();
+        list.push('\n');
+        list
+    }
+
+    fn get_table(&self, headers: &[&str], rows: Vec<Vec<String>>) -> String {
+        let mut table = format!(
+            "| {} |\n|{}\n",
+            headers.join(" | "),
+            " --- |".repeat(headers.len())
+        );
+        for row in rows {
+            let _ = writeln!(table, "| {} |", row.join(" | "));
+        }
+        table.push('\n');
+        table
+    }
+
+    fn get_link(&self, text: &str, href: &str) -> String {
+        // percent-encodes the characters that could end the link target, like spaces and )
+        let mut target = String::new();
+        for byte in href.bytes() {
+            if byte.is_ascii_alphanumeric() || b"-._~#/".contains(&byte) {
+                target.push(byte as char);
+            } else {
+                let _ = write!(target, "%{:02X}", byte);
+            }
+        }
+        format!("[{}]({})", self.get_text(text), target)
+    }
+
+    fn get_text(&self, text: &str) -> String {
+        let mut escaped = String::new();
+        for c in text.chars() {
+            match c {
+                '\\' | '`' | '*' | '_' | '[' | ']' | '#' | '|' => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                }
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '\n' => escaped.push_str("<br>"),
+                _ => escaped.push(c),
+            }
+        }
+        escaped
+    }
+
+    fn get_code(&self, text: &str) -> String {
+        let text = text.replace('|', "\\|").replace('\n', " ");
+        // a code span can not escape backticks, so one that has them is delimited by two
+        if text.contains('`') {
+            format!("`` {} ``", text)
+        } else {
+            format!("`{}`", text)
+        }
+    }
+}
+
+struct HtmlRenderer;
+
+impl PageRenderer for HtmlRenderer {
+    fn get_page(&self, title: &str, body: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+            self.get_text(title),
+            body
+        )
+    }
+
+    fn get_heading(&self, level: usize, text: &str, anchor: Option<&str>) -> String {
+        match anchor {
+            Some(anchor) => format!(
+                "<h{level} id=\"{}\">{}</h{level}>\n",
+                anchor,
+                self.get_text(text)
+            ),
+            None => format!("<h{level}>{}</h{level}>\n", self.get_text(text)),
+        }
+    }
+
+    fn get_paragraph(&self, text: &str) -> String {
+        format!("<p>{}</p>\n", text)
+    }
+
+    fn get_list(&self, items: Vec<String>) -> String {
+        let mut list = String::from("<ul>\n");
+        for item in items {
+            let _ = writeln!(list, "<li>{}</li>", item);
+        }
+        list.push_str("</ul>\n");
+        list
+    }
+
+    fn get_table(&self, headers: &[&str], rows: Vec<Vec<String>>) -> String {
+        let mut table = String::from("<table>\n<tr>");
+        for header in headers {
+            let _ = write!(table, "<th>{}</th>", self.get_text(header));
+        }
+        table.push_str("</tr>\n");
+        for row in rows {
+            table.push_str("<tr>");
+            for cell in row {
+                let _ = write!(table, "<td>{}</td>", cell);
+            }
+            table.push_str("</tr>\n");
+        }
+        table.push_str("</table>\n");
+        table
+    }
+
+    fn get_link(&self, text: &str, href: &str) -> String {
+        format!(
+            "<a href=\"{}\">{}</a>",
+            self.get_text(href),
+            self.get_text(text)
+        )
+    }
+
+    fn get_text(&self, text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    fn get_code(&self, text: &str) -> String {
+        format!("<code>{}</code>", self.get_text(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    // wraps rows the way the connector returns them: every field in a value object
+    fn get_response(rows: Value) -> Value {
+        let rows: Vec<Value> = rows
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| {
+                Value::Object(
+                    row.as_object()
+                        .unwrap()
+                        .iter()
+                        .map(|(column, value)| (column.clone(), json!({ "value": value })))
+                        .collect(),
+                )
+            })
+            .collect();
+        json!([{ "rows": rows }])
+    }
+
+    fn get_column(table: &str, position: i64, name: &str, data_type: &str) -> Value {
+        json!({
+            "table_schema": "public",
+            "table_name": table,
+            "column_name": name,
+            "data_type": data_type,
+            "is_nullable": false,
+            "column_default": null,
+            "ordinal_position": position,
+            "character_maximum_length": null,
+            "numeric_precision": null,
+            "numeric_scale": null,
+            "comment": null,
+        })
+    }
+
+    fn get_test_dictionary() -> DataDictionary {
+        let mut note = get_column("orders", 3, "note", "character varying");
+        note["is_nullable"] = json!(true);
+        note["character_maximum_length"] = json!(20);
+        note["comment"] = json!("free text | shown to *staff*");
+        let mut total = get_column("orders", 4, "total", "numeric");
+        total["numeric_precision"] = json!(10);
+        total["numeric_scale"] = json!(2);
+        let mut id = get_column("orders", 1, "id", "integer");
+        id["column_default"] = json!("nextval('orders_id_seq'::regclass)");
+
+        let mut dictionary = DataDictionary::default();
+        dictionary
+            .add_response(
+                TABLES,
+                &get_response(json!([
+                    {
+                        "table_schema": "public",
+                        "table_name": "orders",
+                        "table_type": "BASE TABLE",
+                        "comment": "orders <placed> by customers",
+                    },
+                    {
+                        "table_schema": "public",
+                        "table_name": "customers",
+                        "table_type": "BASE TABLE",
+                        "comment": null,
+                    },
+                    {
+                        "table_schema": "audit",
+                        "table_name": "log entries",
+                        "table_type": "VIEW",
+                        "comment": null,
+                    },
+                ])),
+            )
+            .unwrap();
+        dictionary
+            .add_response(
+                COLUMNS,
+                &get_response(json!([
+                    total,
+                    note,
+                    get_column("orders", 2, "customer_id", "integer"),
+                    id,
+                    get_column("customers", 1, "id", "integer"),
+                ])),
+            )
+            .unwrap();
+        dictionary
+            .add_response(
+                INDEXES,
+                &get_response(json!([
+                    {
+                        "table_schema": "public",
+                        "table_name": "orders",
+                        "index_name": "orders_pkey",
+                        "is_primary": true,
+                        "is_unique": true,
+                        "key_columns": ["id"],
+                        "definition": "CREATE UNIQUE INDEX orders_pkey ON public.orders USING btree (id)",
+                    },
+                ])),
+            )
+            .unwrap();
+        dictionary
+            .add_response(
+                FOREIGN_KEYS,
+                &get_response(json!([
+                    {
+                        "schema_from": "public",
+                        "table_from": "orders",
+                        "fkey_name": "orders_customer_id_fkey",
+                        "schema_to": "public",
+                        "table_to": "customers",
+                        "column_mapping": [{ "from": "customer_id", "to": "id" }],
+                    },
+                ])),
+            )
+            .unwrap();
+        dictionary
+    }
+
+    fn get_page(pages: &[(String, String)], name: &str) -> String {
+        pages
+            .iter()
+            .find(|(file_name, _)| file_name == name)
+            .map(|(_, page)| page.clone())
+            .unwrap_or_else(|| panic!("there should be a page {}", name))
+    }
+
+    #[test]
+    fn requests_read_the_catalog_tables_without_partitions() {
+        let requests = get_data_dictionary_requests();
+        let tables: Vec<&str> = requests.iter().map(|r| &r.table[..]).collect();
+        assert_eq!(tables, vec![TABLES, COLUMNS, INDEXES, FOREIGN_KEYS]);
+        assert!(matches!(
+            requests[0].arguments.get("exclude_partitions"),
+            Some(models::Argument::Literal {
+                value: Value::Bool(true)
+            })
+        ));
+        let fields = requests[1].query.fields.as_ref().unwrap();
+        assert!(fields.contains_key("ordinal_position") && fields.contains_key("comment"));
+    }
+
+    #[test]
+    fn responses_of_other_tables_are_rejected() {
+        let mut dictionary = DataDictionary::default();
+        assert!(dictionary
+            .add_response("roles", &get_response(json!([])))
+            .is_err());
+        assert!(dictionary
+            .add_response(TABLES, &get_response(json!([{ "table_name": "orders" }])))
+            .is_err());
+    }
+
+    #[test]
+    fn markdown_pages_have_an_index_and_a_page_per_schema() {
+        let pages = get_test_dictionary().get_pages(DataDictionaryFormat::Markdown);
+        let names: Vec<&str> = pages.iter().map(|(name, _)| &name[..]).collect();
+        assert_eq!(names, vec!["index.md", "audit.md", "public.md"]);
+        assert_eq!(
+            get_page(&pages, "index.md"),
+            "# Data dictionary\n\n- [audit](audit.md) (1 tables)\n- [public](public.md) (2 tables)\n\n"
+        );
+        assert!(get_page(&pages, "audit.md").contains("- [log entries](#log-entries)\n"));
+    }
+
+    #[test]
+    fn markdown_table_sections_describe_columns_and_keys() {
+        let pages = get_test_dictionary().get_pages(DataDictionaryFormat::Markdown);
+        let page = get_page(&pages, "public.md");
+        // the tables are sorted by name
+        assert!(page.find("## customers").unwrap() < page.find("## orders").unwrap());
+        assert!(page.contains("<a id=\"orders\"></a>\n\n## orders\n\norders &lt;placed&gt; by customers\n\nType: BASE TABLE\n\n"));
+        assert!(page.contains(
+            "### Columns
+
+| Column | Type | Nullable | Default | Key | Comment |
+| --- | --- | --- | --- | --- | --- |
+| `id` | `integer` | no | `nextval('orders_id_seq'::regclass)` | PK |  |
+| `customer_id` | `integer` | no |  | FK |  |
+| `note` | `character varying(20)` | yes |  |  | free text \\| shown to \\*staff\\* |
+| `total` | `numeric(10, 2)` | no |  |  |  |
+"
+        ));
+        assert!(page.contains("| `orders_pkey` | id | primary key | `CREATE UNIQUE INDEX orders_pkey ON public.orders USING btree (id)` |\n"));
+        assert!(page.contains(
+            "| `orders_customer_id_fkey` | customer\\_id → id | [public.customers](public.md#customers) |\n"
+        ));
+        assert!(page.contains(
+            "### Referenced by\n\n| Table | Foreign key | Columns |\n| --- | --- | --- |\n| [public.orders](public.md#orders) | `orders_customer_id_fkey` | customer\\_id → id |\n"
+        ));
+        // customers has no indexes or foreign keys of its own
+        let customers = &page[page.find("## customers").unwrap()..page.find("## orders").unwrap()];
+        assert!(!customers.contains("### Indexes") && !customers.contains("### Foreign keys"));
+    }
+
+    #[test]
+    fn html_pages_are_documents_with_escaped_text() {
+        let pages = get_test_dictionary().get_pages(DataDictionaryFormat::Html);
+        let names: Vec<&str> = pages.iter().map(|(name, _)| &name[..]).collect();
+        assert_eq!(names, vec!["index.html", "audit.html", "public.html"]);
+        let page = get_page(&pages, "public.html");
+        assert!(page.starts_with("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Schema public</title>"));
+        assert!(page.ends_with("</body>\n</html>\n"));
+        assert!(page.contains(
+            "<h2 id=\"orders\">orders</h2>\n<p>orders &lt;placed&gt; by customers</p>\n"
+        ));
+        assert!(page.contains("<tr><th>Column</th><th>Type</th><th>Nullable</th><th>Default</th><th>Key</th><th>Comment</th></tr>\n"));
+        assert!(page.contains("<td><a href=\"public.html#customers\">public.customers</a></td>"));
+        assert!(get_page(&pages, "index.html")
+            .contains("<li><a href=\"audit.html\">audit</a> (1 tables)</li>\n"));
+    }
+
+    #[test]
+    fn markdown_code_spans_keep_backticks_and_pipes() {
+        assert_eq!(MarkdownRenderer.get_code("a|b"), "`a\\|b`");
+        assert_eq!(MarkdownRenderer.get_code("`x`\ny"), "`` `x` y ``");
+        assert_eq!(MarkdownRenderer.get_text("a_b\nc"), "a\\_b<br>c");
+        assert_eq!(get_anchor("order lines/2023"), "order-lines-2023");
+    }
+
+    #[test]
+    fn schema_pages_are_files_of_the_output_directory() {
+        let names =
+            get_page_names(["../x", "a/b", "index", "Public", "public", "ventes é"].into_iter());
+        assert_eq!(names["../x"], "-2e-2e-2fx");
+        assert_eq!(names["a/b"], "a-2fb");
+        // the index page and a schema that only differs in case are not overwritten
+        assert_eq!(names["index"], "index~2");
+        assert_eq!(names["Public"], "Public");
+        assert_eq!(names["public"], "public~2");
+        assert_eq!(names["ventes é"], "ventes-20-c3-a9");
+    }
+
+    #[test]
+    fn links_use_the_file_names_of_the_pages() {
+        let mut dictionary = get_test_dictionary();
+        for table in &mut dictionary.tables {
+            table.table_schema = match &table.table_schema[..] {
+                "audit" => "../audit".into(),
+                _ => "index".into(),
+            };
+        }
+        for column in &mut dictionary.columns {
+            column.table_schema = "index".into();
+        }
+        for foreign_key in &mut dictionary.foreign_keys {
+            foreign_key.schema_from = "index".into();
+            foreign_key.schema_to = "public".into();
+        }
+        let pages = dictionary.get_pages(DataDictionaryFormat::Markdown);
+        let names: Vec<&str> = pages.iter().map(|(name, _)| &name[..]).collect();
+        assert_eq!(names, vec!["index.md", "-2e-2e-2faudit.md", "index~2.md"]);
+        assert!(
+            get_page(&pages, "index.md").contains("- [../audit](-2e-2e-2faudit.md) (1 tables)\n")
+        );
+        let page = get_page(&pages, "index~2.md");
+        assert!(page.contains("# Schema index\n"));
+        // the columns of the tables are still found, and the referenced schema has no page to
+        // link to
+        assert!(page.contains("| `customer_id` | `integer` | no |  | FK |  |\n"));
+        assert!(page
+            .contains("| `orders_customer_id_fkey` | customer\\_id → id | public.customers |\n"));
+        assert_eq!(
+            MarkdownRenderer.get_link("a", "b c).md#d"),
+            "[a](b%20c%29.md#d)"
+        );
+    }
+}
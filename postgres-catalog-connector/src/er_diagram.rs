@@ -0,0 +1,882 @@
+use ndc_client::models::{self, QueryRequest};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::arguments::get_argument_value;
+use crate::error::ServerError;
+use crate::rows::{get_response_rows, is_like};
+use crate::tables::FOREIGN_KEYS;
+
+// the name of the command that renders an ER diagram of the tables of a database
+pub const ER_DIAGRAM: &str = "er_diagram";
+
+// The tables that can be in a diagram, as a JSON array, with their key columns: the columns
+// of their primary key, unique constraints and foreign keys. unique_keys are the column sets
+// of the primary key and unique constraints, which make a foreign key one-to-one.
+pub const ER_DIAGRAM_TABLES_QUERY: &str = "
+SELECT coalesce(json_agg(t ORDER BY t.table_schema, t.table_name), '[]')
+FROM (
+    SELECT
+        n.nspname AS table_schema,
+        c.relname AS table_name,
+        (
+            SELECT coalesce(json_agg(k ORDER BY k.position), '[]')
+            FROM (
+                SELECT
+                    a.attnum AS position,
+                    a.attname AS column_name,
+                    ty.typname AS data_type,
+                    NOT a.attnotnull AS is_nullable,
+                    EXISTS (
+                        SELECT 1 FROM pg_constraint AS con
+                        WHERE con.conrelid = c.oid AND con.contype = 'p' AND a.attnum = ANY (con.conkey)
+                    ) AS is_primary_key,
+                    EXISTS (
+                        SELECT 1 FROM pg_constraint AS con
+                        WHERE con.conrelid = c.oid AND con.contype = 'f' AND a.attnum = ANY (con.conkey)
+                    ) AS is_foreign_key,
+                    EXISTS (
+                        SELECT 1 FROM pg_constraint AS con
+                        WHERE con.conrelid = c.oid AND con.contype = 'u' AND a.attnum = ANY (con.conkey)
+                    ) AS is_unique
+                FROM pg_attribute AS a
+                JOIN pg_type AS ty ON ty.oid = a.atttypid
+                WHERE a.attrelid = c.oid AND a.attnum > 0 AND NOT a.attisdropped
+            ) AS k
+            WHERE k.is_primary_key OR k.is_foreign_key OR k.is_unique
+        ) AS columns,
+        (
+            SELECT coalesce(json_agg(u.column_names), '[]')
+            FROM (
+                SELECT (
+                    SELECT json_agg(a.attname ORDER BY a.attname)
+                    FROM pg_attribute AS a
+                    WHERE a.attrelid = con.conrelid AND a.attnum = ANY (con.conkey)
+                ) AS column_names
+                FROM pg_constraint AS con
+                WHERE con.conrelid = c.oid AND con.contype IN ('p', 'u')
+            ) AS u
+        ) AS unique_keys
+    FROM pg_class AS c
+    JOIN pg_namespace AS n ON n.oid = c.relnamespace
+    WHERE c.relkind IN ('r', 'p', 'f')
+        AND NOT c.relispartition
+        AND n.nspname NOT LIKE 'pg\\_%'
+        AND n.nspname <> 'information_schema'
+) AS t
+";
+
+// the notations a diagram can be rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErDiagramFormat {
+    Mermaid,
+    Dot,
+    PlantUml,
+}
+
+impl FromStr for ErDiagramFormat {
+    type Err = ServerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mermaid" => Ok(ErDiagramFormat::Mermaid),
+            "dot" => Ok(ErDiagramFormat::Dot),
+            "plantuml" => Ok(ErDiagramFormat::PlantUml),
+            _ => Err(ServerError::BadRequest(format!(
+                "unknown format {}; expected mermaid, dot or plantuml",
+                s
+            ))),
+        }
+    }
+}
+
+// the arguments of the er_diagram command
+#[derive(Debug, Clone)]
+pub struct ErDiagramArguments {
+    pub format: ErDiagramFormat,
+    // only draw the tables of this schema
+    pub schema: Option<String>,
+    // only draw the tables whose name matches this LIKE pattern
+    pub table_pattern: Option<String>,
+    // only draw this table, and the tables up to depth foreign keys away from it
+    pub table: Option<String>,
+    pub depth: u64,
+}
+
+impl ErDiagramArguments {
+    pub fn from_request(request: &QueryRequest) -> Result<Self, ServerError> {
+        let variables = request.variables.clone().unwrap_or_default();
+        let get_string_argument =
+            |key: &str| match get_argument_value(&request.arguments, &variables, key) {
+                Some(Value::Null) | None => Ok(None),
+                Some(Value::String(value)) => Ok(Some(value.clone())),
+                Some(_) => Err(ServerError::BadRequest(format!(
+                    "{} should be a string",
+                    key
+                ))),
+            };
+        let format = match get_string_argument("format")? {
+            Some(format) => ErDiagramFormat::from_str(&format)?,
+            None => ErDiagramFormat::Mermaid,
+        };
+        let depth = match get_argument_value(&request.arguments, &variables, "depth") {
+            Some(Value::Null) | None => 1,
+            Some(value) => value.as_u64().ok_or_else(|| {
+                ServerError::BadRequest("depth should be a non-negative integer".into())
+            })?,
+        };
+        Ok(ErDiagramArguments {
+            format,
+            schema: get_string_argument("schema")?,
+            table_pattern: get_string_argument("table_pattern")?,
+            table: get_string_argument("table")?,
+            depth,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ErTable {
+    pub table_schema: String,
+    pub table_name: String,
+    pub columns: Vec<ErColumn>,
+    pub unique_keys: Vec<BTreeSet<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ErColumn {
+    pub column_name: String,
+    pub data_type: String,
+    pub is_nullable: bool,
+    pub is_primary_key: bool,
+    pub is_foreign_key: bool,
+    pub is_unique: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ErForeignKey {
+    pub schema_from: String,
+    pub table_from: String,
+    pub fkey_name: String,
+    pub schema_to: String,
+    pub table_to: String,
+    pub column_mapping: Vec<ErColumnMapping>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ErColumnMapping {
+    pub from: String,
+    pub to: String,
+}
+
+// gets the request that reads the foreign keys of the database, with their column mappings
+pub fn get_foreign_keys_request() -> QueryRequest {
+    let fields = [
+        "schema_from",
+        "table_from",
+        "fkey_name",
+        "schema_to",
+        "table_to",
+        "column_mapping",
+    ]
+    .into_iter()
+    .map(|column| {
+        (
+            column.to_string(),
+            models::Field::Column {
+                column: column.into(),
+                arguments: HashMap::new(),
+            },
+        )
+    })
+    .collect();
+    QueryRequest {
+        table: FOREIGN_KEYS.into(),
+        query: models::Query {
+            aggregates: None,
+            fields: Some(fields),
+            limit: None,
+            offset: None,
+            order_by: None,
+            predicate: None,
+        },
+        arguments: HashMap::new(),
+        table_relationships: HashMap::new(),
+        variables: None,
+    }
+}
+
+// gets the foreign keys from the response to the foreign keys request
+pub fn get_foreign_keys(response: &Value) -> Result<Vec<ErForeignKey>, ServerError> {
+    get_response_rows(response)?
+        .into_iter()
+        .map(|row| {
+            serde_json::from_value(Value::Object(row))
+                .map_err(|err| ServerError::Internal(err.to_string()))
+        })
+        .collect()
+}
+
+// a relationship between two tables of the diagram: the table of a foreign key, and the table
+// it references
+struct ErRelationship<'a> {
+    from: usize,
+    to: usize,
+    foreign_key: &'a ErForeignKey,
+    // the foreign key can be null, so a row does not have to reference a row
+    is_optional: bool,
+    // the foreign key columns are unique, so a row is referenced by at most one row
+    is_one_to_one: bool,
+}
+
+// renders the tables that pass the filters of the arguments, and the foreign keys between them
+pub fn get_er_diagram(
+    tables: Vec<ErTable>,
+    foreign_keys: &[ErForeignKey],
+    arguments: &ErDiagramArguments,
+) -> Result<String, ServerError> {
+    let mut tables: Vec<ErTable> = tables
+        .into_iter()
+        .filter(|t| match &arguments.schema {
+            Some(schema) => &t.table_schema == schema,
+            None => true,
+        })
+        .filter(|t| match &arguments.table_pattern {
+            Some(pattern) => is_like(
+                &Value::String(t.table_name.clone()),
+                &Value::String(pattern.clone()),
+                false,
+            ),
+            None => true,
+        })
+        .collect();
+    tables.sort_by(|a, b| (&a.table_schema, &a.table_name).cmp(&(&b.table_schema, &b.table_name)));
+
+    let mut relationships = get_relationships(&tables, foreign_keys);
+    if let Some(table) = &arguments.table {
+        let center = get_table_position(&tables, table)?;
+        let neighborhood = get_neighborhood(center, &relationships, arguments.depth);
+        // keep the positions of the relationships pointing to the same tables
+        let positions: HashMap<usize, usize> = neighborhood
+            .iter()
+            .enumerate()
+            .map(|(new, old)| (*old, new))
+            .collect();
+        tables = tables
+            .into_iter()
+            .enumerate()
+            .filter(|(position, _)| positions.contains_key(position))
+            .map(|(_, table)| table)
+            .collect();
+        relationships = relationships
+            .into_iter()
+            .filter(|r| positions.contains_key(&r.from) && positions.contains_key(&r.to))
+            .map(|r| ErRelationship {
+                from: positions[&r.from],
+                to: positions[&r.to],
+                ..r
+            })
+            .collect();
+    }
+
+    let ids = get_entity_ids(&tables);
+    Ok(match arguments.format {
+        ErDiagramFormat::Mermaid => get_mermaid_diagram(&tables, &ids, &relationships),
+        ErDiagramFormat::Dot => get_dot_diagram(&tables, &relationships),
+        ErDiagramFormat::PlantUml => get_plantuml_diagram(&tables, &ids, &relationships),
+    })
+}
+
+fn get_relationships<'a>(
+    tables: &[ErTable],
+    foreign_keys: &'a [ErForeignKey],
+) -> Vec<ErRelationship<'a>> {
+    let positions: HashMap<(&str, &str), usize> = tables
+        .iter()
+        .enumerate()
+        .map(|(position, t)| ((&t.table_schema[..], &t.table_name[..]), position))
+        .collect();
+    let mut relationships: Vec<ErRelationship> = foreign_keys
+        .iter()
+        .filter_map(|fk| {
+            let from = *positions.get(&(&fk.schema_from[..], &fk.table_from[..]))?;
+            let to = *positions.get(&(&fk.schema_to[..], &fk.table_to[..]))?;
+            let columns: BTreeSet<String> =
+                fk.column_mapping.iter().map(|m| m.from.clone()).collect();
+            Some(ErRelationship {
+                from,
+                to,
+                foreign_key: fk,
+                is_optional: tables[from]
+                    .columns
+                    .iter()
+                    .any(|c| c.is_nullable && columns.contains(&c.column_name)),
+                is_one_to_one: tables[from].unique_keys.contains(&columns),
+            })
+        })
+        .collect();
+    relationships.sort_by(|a, b| {
+        (a.from, &a.foreign_key.fkey_name).cmp(&(b.from, &b.foreign_key.fkey_name))
+    });
+    relationships
+}
+
+// finds a table by its name, or by its schema and name as schema.name
+fn get_table_position(tables: &[ErTable], table: &str) -> Result<usize, ServerError> {
+    let matches: Vec<usize> = tables
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| {
+            t.table_name == table || format!("{}.{}", t.table_schema, t.table_name) == table
+        })
+        .map(|(position, _)| position)
+        .collect();
+    match matches[..] {
+        [position] => Ok(position),
+        [] => Err(ServerError::BadRequest(format!(
+            "the diagram has no table {}",
+            table
+        ))),
+        _ => Err(ServerError::BadRequest(format!(
+            "there is a table {} in more than one schema; use schema.name",
+            table
+        ))),
+    }
+}
+
+// gets the positions of the tables that are at most depth foreign keys away from the center
+// table, in either direction, in the order of the tables
+fn get_neighborhood(center: usize, relationships: &[ErRelationship], depth: u64) -> Vec<usize> {
+    let mut distances = BTreeMap::from_iter([(center, 0)]);
+    let mut pending = VecDeque::from_iter([center]);
+    while let Some(position) = pending.pop_front() {
+        let distance = distances[&position];
+        if distance == depth {
+            continue;
+        }
+        for r in relationships {
+            let neighbor = if r.from == position {
+                r.to
+            } else if r.to == position {
+                r.from
+            } else {
+                continue;
+            };
+            if let Entry::Vacant(entry) = distances.entry(neighbor) {
+                entry.insert(distance + 1);
+                pending.push_back(neighbor);
+            }
+        }
+    }
+    distances.into_keys().collect()
+}
+
+// gets the identifiers of the tables in the notations that need them to be plain words: the
+// schema and name of the table, with the other characters replaced by underscores
+fn get_entity_ids(tables: &[ErTable]) -> Vec<String> {
+    let mut used = BTreeSet::new();
+    tables
+        .iter()
+        .map(|t| {
+            let base: String = format!("{}_{}", t.table_schema, t.table_name)
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            let mut id = base.clone();
+            let mut suffix = 2;
+            while !used.insert(id.clone()) {
+                id = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            id
+        })
+        .collect()
+}
+
+fn get_qualified_name(table: &ErTable) -> String {
+    format!("{}.{}", table.table_schema, table.table_name)
+}
+
+fn get_key_markers(column: &ErColumn) -> Vec<&'static str> {
+    [
+        (column.is_primary_key, "PK"),
+        (column.is_foreign_key, "FK"),
+        (column.is_unique, "UK"),
+    ]
+    .into_iter()
+    .filter(|(is_key, _)| *is_key)
+    .map(|(_, marker)| marker)
+    .collect()
+}
+
+fn get_word(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+// Mermaid erDiagram, with crow's foot relationships from the referenced table to the table of
+// the foreign key
+fn get_mermaid_diagram(
+    tables: &[ErTable],
+    ids: &[String],
+    relationships: &[ErRelationship],
+) -> String {
+    let mut diagram = String::from("erDiagram\n");
+    for (table, id) in tables.iter().zip(ids) {
+        // an entity without key columns is declared without a block
+        if table.columns.is_empty() {
+            let _ = writeln!(diagram, "    {}[\"{}\"]", id, get_qualified_name(table));
+            continue;
+        }
+        let _ = writeln!(diagram, "    {}[\"{}\"] {{", id, get_qualified_name(table));
+        for column in &table.columns {
+            let _ = writeln!(
+                diagram,
+                "        {} {} {}",
+                get_word(&column.data_type),
+                get_word(&column.column_name),
+                get_key_markers(column).join(", ")
+            );
+        }
+        diagram.push_str("    }\n");
+    }
+    for r in relationships {
+        let _ = writeln!(
+            diagram,
+            "    {} {}--{} {} : \"{}\"",
+            ids[r.to],
+            if r.is_optional { "|o" } else { "||" },
+            if r.is_one_to_one { "o|" } else { "o{" },
+            ids[r.from],
+            r.foreign_key.fkey_name.replace('"', "'")
+        );
+    }
+    diagram
+}
+
+// Graphviz DOT, with a table shaped node per table and an edge from the table of a foreign key
+// to the table it references
+fn get_dot_diagram(tables: &[ErTable], relationships: &[ErRelationship]) -> String {
+    let mut diagram = String::from(
+        "digraph er_diagram {\n    graph [rankdir=LR];\n    node [shape=plaintext];\n",
+    );
+    for table in tables {
+        let mut label = format!(
+            "<table border=\"0\" cellborder=\"1\" cellspacing=\"0\"><tr><td bgcolor=\"lightgrey\"><b>{}</b></td></tr>",
+            get_html_text(&get_qualified_name(table))
+        );
+        for column in &table.columns {
+            let _ = write!(
+                label,
+                "<tr><td align=\"left\">{}: {} ({})</td></tr>",
+                get_html_text(&column.column_name),
+                get_html_text(&column.data_type),
+                get_key_markers(column).join(", ")
+            );
+        }
+        label.push_str("</table>");
+        let _ = writeln!(
+            diagram,
+            "    {} [label=<{}>];",
+            get_dot_id(&get_qualified_name(table)),
+            label
+        );
+    }
+    for r in relationships {
+        let _ = writeln!(
+            diagram,
+            "    {} -> {} [label={}, arrowhead={}, arrowtail={}, dir=both];",
+            get_dot_id(&get_qualified_name(&tables[r.from])),
+            get_dot_id(&get_qualified_name(&tables[r.to])),
+            get_dot_id(&r.foreign_key.fkey_name),
+            if r.is_optional { "teeodot" } else { "teetee" },
+            if r.is_one_to_one {
+                "teeodot"
+            } else {
+                "crowodot"
+            }
+        );
+    }
+    diagram.push_str("}\n");
+    diagram
+}
+
+fn get_dot_id(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn get_html_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// PlantUML entities, with the primary key columns above the line and the other key columns
+// below it, and information engineering relationships like the Mermaid ones
+fn get_plantuml_diagram(
+    tables: &[ErTable],
+    ids: &[String],
+    relationships: &[ErRelationship],
+) -> String {
+    let mut diagram = String::from("@startuml\nhide circle\nskinparam linetype ortho\n\n");
+    for (table, id) in tables.iter().zip(ids) {
+        let _ = writeln!(
+            diagram,
+            "entity \"{}\" as {} {{",
+            get_qualified_name(table).replace('"', "'"),
+            id
+        );
+        let (primary_key, others): (Vec<&ErColumn>, Vec<&ErColumn>) =
+            table.columns.iter().partition(|c| c.is_primary_key);
+        for column in &primary_key {
+            diagram.push_str(&get_plantuml_attribute(column));
+        }
+        diagram.push_str("  --\n");
+        for column in &others {
+            diagram.push_str(&get_plantuml_attribute(column));
+        }
+        diagram.push_str("}\n");
+    }
+    if !relationships.is_empty() {
+        diagram.push('\n');
+    }
+    for r in relationships {
+        let _ = writeln!(
+            diagram,
+            "{} {}--{} {} : {}",
+            ids[r.to],
+            if r.is_optional { "|o" } else { "||" },
+            if r.is_one_to_one { "o|" } else { "o{" },
+            ids[r.from],
+            r.foreign_key.fkey_name
+        );
+    }
+    diagram.push_str("@enduml\n");
+    diagram
+}
+
+// a column of an entity; * marks the columns that cannot be null
+fn get_plantuml_attribute(column: &ErColumn) -> String {
+    format!(
+        "  {}{} : {} {}\n",
+        if column.is_nullable { "" } else { "* " },
+        column.column_name,
+        column.data_type,
+        get_key_markers(column)
+            .iter()
+            .map(|marker| format!("<<{}>>", marker))
+            .collect::<Vec<_>>()
+            .join(" ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn get_column(name: &str, data_type: &str, markers: &[&str], is_nullable: bool) -> ErColumn {
+        ErColumn {
+            column_name: name.into(),
+            data_type: data_type.into(),
+            is_nullable,
+            is_primary_key: markers.contains(&"PK"),
+            is_foreign_key: markers.contains(&"FK"),
+            is_unique: markers.contains(&"UK"),
+        }
+    }
+
+    fn get_table(schema: &str, name: &str, columns: Vec<ErColumn>) -> ErTable {
+        let unique_keys = columns
+            .iter()
+            .filter(|c| c.is_primary_key || c.is_unique)
+            .map(|c| BTreeSet::from_iter([c.column_name.clone()]))
+            .collect();
+        ErTable {
+            table_schema: schema.into(),
+            table_name: name.into(),
+            columns,
+            unique_keys,
+        }
+    }
+
+    fn get_foreign_key(
+        from: (&str, &str),
+        name: &str,
+        to: (&str, &str),
+        column: &str,
+    ) -> ErForeignKey {
+        ErForeignKey {
+            schema_from: from.0.into(),
+            table_from: from.1.into(),
+            fkey_name: name.into(),
+            schema_to: to.0.into(),
+            table_to: to.1.into(),
+            column_mapping: vec![ErColumnMapping {
+                from: column.into(),
+                to: "id".into(),
+            }],
+        }
+    }
+
+    // customers <- orders <- order_lines, orders <- invoices (one-to-one), and an unrelated
+    // table in another schema
+    fn get_test_tables() -> (Vec<ErTable>, Vec<ErForeignKey>) {
+        let tables = vec![
+            get_table(
+                "public",
+                "orders",
+                vec![
+                    get_column("id", "integer", &["PK"], false),
+                    get_column("customer_id", "integer", &["FK"], true),
+                ],
+            ),
+            get_table(
+                "public",
+                "customers",
+                vec![get_column("id", "integer", &["PK"], false)],
+            ),
+            get_table(
+                "public",
+                "order_lines",
+                vec![
+                    get_column("id", "integer", &["PK"], false),
+                    get_column("order_id", "integer", &["FK"], false),
+                ],
+            ),
+            get_table(
+                "public",
+                "invoices",
+                vec![get_column("order_id", "integer", &["FK", "UK"], false)],
+            ),
+            get_table("audit", "events", vec![]),
+        ];
+        let foreign_keys = vec![
+            get_foreign_key(
+                ("public", "orders"),
+                "orders_customer_id_fkey",
+                ("public", "customers"),
+                "customer_id",
+            ),
+            get_foreign_key(
+                ("public", "order_lines"),
+                "order_lines_order_id_fkey",
+                ("public", "orders"),
+                "order_id",
+            ),
+            get_foreign_key(
+                ("public", "invoices"),
+                "invoices_order_id_fkey",
+                ("public", "orders"),
+                "order_id",
+            ),
+        ];
+        (tables, foreign_keys)
+    }
+
+    fn get_arguments(format: ErDiagramFormat) -> ErDiagramArguments {
+        ErDiagramArguments {
+            format,
+            schema: None,
+            table_pattern: None,
+            table: None,
+            depth: 1,
+        }
+    }
+
+    fn get_diagram(arguments: &ErDiagramArguments) -> Result<String, ServerError> {
+        let (tables, foreign_keys) = get_test_tables();
+        get_er_diagram(tables, &foreign_keys, arguments)
+    }
+
+    fn get_request(arguments: Value) -> QueryRequest {
+        serde_json::from_value(json!({
+            "table": ER_DIAGRAM,
+            "query": { "fields": {} },
+            "arguments": arguments,
+            "table_relationships": {},
+        }))
+        .expect("the request should be valid")
+    }
+
+    #[test]
+    fn arguments_have_defaults_and_are_validated() {
+        let arguments = ErDiagramArguments::from_request(&get_request(json!({}))).unwrap();
+        assert_eq!(arguments.format, ErDiagramFormat::Mermaid);
+        assert_eq!(arguments.depth, 1);
+        assert!(arguments.schema.is_none() && arguments.table.is_none());
+
+        let arguments = ErDiagramArguments::from_request(&get_request(json!({
+            "format": { "type": "literal", "value": "plantuml" },
+            "table": { "type": "literal", "value": "public.orders" },
+            "depth": { "type": "literal", "value": 2 },
+        })))
+        .unwrap();
+        assert_eq!(arguments.format, ErDiagramFormat::PlantUml);
+        assert_eq!(arguments.table.as_deref(), Some("public.orders"));
+        assert_eq!(arguments.depth, 2);
+
+        for invalid in [
+            json!({ "format": { "type": "literal", "value": "svg" } }),
+            json!({ "depth": { "type": "literal", "value": -1 } }),
+            json!({ "schema": { "type": "literal", "value": 1 } }),
+        ] {
+            assert!(ErDiagramArguments::from_request(&get_request(invalid)).is_err());
+        }
+    }
+
+    #[test]
+    fn mermaid_diagram_has_sorted_entities_and_cardinalities() {
+        let diagram = get_diagram(&get_arguments(ErDiagramFormat::Mermaid)).unwrap();
+        assert_eq!(
+            diagram,
+            "erDiagram
+    audit_events[\"audit.events\"]
+    public_customers[\"public.customers\"] {
+        integer id PK
+    }
+    public_invoices[\"public.invoices\"] {
+        integer order_id FK, UK
+    }
+    public_order_lines[\"public.order_lines\"] {
+        integer id PK
+        integer order_id FK
+    }
+    public_orders[\"public.orders\"] {
+        integer id PK
+        integer customer_id FK
+    }
+    public_orders ||--o| public_invoices : \"invoices_order_id_fkey\"
+    public_orders ||--o{ public_order_lines : \"order_lines_order_id_fkey\"
+    public_customers |o--o{ public_orders : \"orders_customer_id_fkey\"
+"
+        );
+    }
+
+    #[test]
+    fn schema_and_table_pattern_filter_tables_and_their_foreign_keys() {
+        let mut arguments = get_arguments(ErDiagramFormat::Mermaid);
+        arguments.schema = Some("public".into());
+        arguments.table_pattern = Some("order%".into());
+        let diagram = get_diagram(&arguments).unwrap();
+        assert!(diagram.contains("public_orders[") && diagram.contains("public_order_lines["));
+        assert!(!diagram.contains("public_customers") && !diagram.contains("audit_events"));
+        assert!(diagram.contains("order_lines_order_id_fkey"));
+        assert!(!diagram.contains("orders_customer_id_fkey"));
+    }
+
+    #[test]
+    fn table_keeps_the_tables_up_to_depth_foreign_keys_away() {
+        let mut arguments = get_arguments(ErDiagramFormat::Mermaid);
+        arguments.table = Some("order_lines".into());
+        let diagram = get_diagram(&arguments).unwrap();
+        assert!(diagram.contains("public_order_lines[") && diagram.contains("public_orders["));
+        assert!(!diagram.contains("public_customers[") && !diagram.contains("public_invoices["));
+        assert!(diagram.contains("public_orders ||--o{ public_order_lines"));
+
+        arguments.depth = 2;
+        let diagram = get_diagram(&arguments).unwrap();
+        assert!(diagram.contains("public_customers[") && diagram.contains("public_invoices["));
+        assert!(!diagram.contains("audit_events"));
+        assert!(diagram.contains("public_customers |o--o{ public_orders"));
+
+        arguments.depth = 0;
+        arguments.table = Some("public.customers".into());
+        let diagram = get_diagram(&arguments).unwrap();
+        assert_eq!(
+            diagram,
+            "erDiagram\n    public_customers[\"public.customers\"] {\n        integer id PK\n    }\n"
+        );
+    }
+
+    #[test]
+    fn unknown_and_ambiguous_tables_are_rejected() {
+        let mut arguments = get_arguments(ErDiagramFormat::Mermaid);
+        arguments.table = Some("products".into());
+        assert!(get_diagram(&arguments).is_err());
+
+        let (mut tables, foreign_keys) = get_test_tables();
+        tables.push(get_table("archive", "orders", vec![]));
+        arguments.table = Some("orders".into());
+        assert!(get_er_diagram(tables.clone(), &foreign_keys, &arguments).is_err());
+        arguments.table = Some("archive.orders".into());
+        assert!(get_er_diagram(tables, &foreign_keys, &arguments).is_ok());
+    }
+
+    #[test]
+    fn entity_ids_are_words_and_unique() {
+        let tables = vec![
+            get_table("public", "a-b", vec![]),
+            get_table("public", "a_b", vec![]),
+            get_table("public_a", "b", vec![]),
+        ];
+        assert_eq!(
+            get_entity_ids(&tables),
+            vec!["public_a_b", "public_a_b_2", "public_a_b_3"]
+        );
+    }
+
+    #[test]
+    fn dot_diagram_escapes_names() {
+        let tables = vec![get_table(
+            "public",
+            "a\"b",
+            vec![get_column("x<y", "text", &[], true)],
+        )];
+        let mut arguments = get_arguments(ErDiagramFormat::Dot);
+        arguments.table_pattern = Some("a%".into());
+        let diagram = get_er_diagram(tables, &[], &arguments).unwrap();
+        assert!(diagram.starts_with("digraph er_diagram {\n"));
+        assert!(diagram.contains("    \"public.a\\\"b\" [label=<"));
+        assert!(diagram.contains("<b>public.a&quot;b</b>"));
+        assert!(diagram.contains("x&lt;y: text ()"));
+        assert!(diagram.ends_with("}\n"));
+
+        let diagram = get_diagram(&get_arguments(ErDiagramFormat::Dot)).unwrap();
+        assert!(diagram.contains(
+            "    \"public.orders\" -> \"public.customers\" [label=\"orders_customer_id_fkey\", arrowhead=teeodot, arrowtail=crowodot, dir=both];"
+        ));
+        assert!(diagram.contains(
+            "    \"public.invoices\" -> \"public.orders\" [label=\"invoices_order_id_fkey\", arrowhead=teetee, arrowtail=teeodot, dir=both];"
+        ));
+    }
+
+    #[test]
+    fn plantuml_diagram_separates_primary_key_columns() {
+        let mut arguments = get_arguments(ErDiagramFormat::PlantUml);
+        arguments.table = Some("orders".into());
+        arguments.depth = 0;
+        assert_eq!(
+            get_diagram(&arguments).unwrap(),
+            "@startuml
+hide circle
+skinparam linetype ortho
+
+entity \"public.orders\" as public_orders {
+  * id : integer <<PK>>
+  --
+  customer_id : integer <<FK>>
+}
+@enduml
+"
+        );
+
+        let diagram = get_diagram(&get_arguments(ErDiagramFormat::PlantUml)).unwrap();
+        assert!(
+            diagram.contains("\npublic_customers |o--o{ public_orders : orders_customer_id_fkey\n")
+        );
+        assert!(diagram.ends_with("@enduml\n"));
+    }
+}
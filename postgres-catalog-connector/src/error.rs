@@ -5,12 +5,23 @@ use axum::{
 };
 use serde::Serialize;
 
+#[derive(Debug)]
 pub enum ServerError {
     Internal(String),
     DatabaseError(String),
     BadRequest(String),
 }
 
+impl std::fmt::Display for ServerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServerError::Internal(msg)
+            | ServerError::DatabaseError(msg)
+            | ServerError::BadRequest(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
 #[derive(Serialize)]
 struct JsonErrorResponse {
     message: String,
@@ -0,0 +1,43 @@
+use cc_postgres::data_dictionary::{self, DataDictionary, DataDictionaryFormat};
+use cc_postgres::error::ServerError;
+use ndc_client::models;
+use std::fs;
+use std::path::Path;
+
+use crate::routes::post_query::resolve_query_request;
+
+// writes the data dictionary of a database to the output directory. The catalog is read by
+// running query requests the way the /query route does, so the pages show what the API returns.
+pub async fn export_data_dictionary(
+    database_url: Option<&String>,
+    format: DataDictionaryFormat,
+    output: &Path,
+) -> Result<(), ServerError> {
+    let mut dictionary = DataDictionary::default();
+    for mut request in data_dictionary::get_data_dictionary_requests() {
+        if let Some(database_url) = database_url {
+            request.arguments.insert(
+                "database_url".into(),
+                models::Argument::Literal {
+                    value: database_url.clone().into(),
+                },
+            );
+        }
+        let response = resolve_query_request(&request).await?;
+        let response = serde_json::to_value(response.0)
+            .map_err(|err| ServerError::Internal(err.to_string()))?;
+        dictionary.add_response(&request.table, &response)?;
+    }
+
+    fs::create_dir_all(output).map_err(|err| {
+        ServerError::Internal(format!("could not create {}: {}", output.display(), err))
+    })?;
+    for (file_name, contents) in dictionary.get_pages(format) {
+        let path = output.join(file_name);
+        fs::write(&path, contents).map_err(|err| {
+            ServerError::Internal(format!("could not write {}: {}", path.display(), err))
+        })?;
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,283 @@
+use ndc_client::models::{self, OrderByTarget, OrderDirection};
+use serde_json::{json, Number, Value};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::error::ServerError;
+use crate::tables::DATABASE_NAME_COLUMN;
+
+// the databases a query fans out to: every database that accepts connections from the role
+pub const FAN_OUT_DATABASES_QUERY: &str = "
+SELECT datname
+FROM pg_database
+WHERE datallowconn AND NOT datistemplate AND has_database_privilege(oid, 'CONNECT')
+ORDER BY datname
+";
+
+// the fields added to the query to sort the merged rows with, removed again after merging
+const ORDER_BY_FIELD_PREFIX: &str = "_order_by_";
+
+// gets the query that is run against each database. Every row is tagged with the database it
+// comes from, the order_by columns are added as fields so that the rows can be merged, and
+// each database returns enough rows to fill the requested page on its own.
+pub fn get_fan_out_query(query: &models::Query) -> models::Query {
+    let fields = query.fields.clone().map(|mut fields| {
+        fields
+            .entry(DATABASE_NAME_COLUMN.into())
+            .or_insert_with(|| get_column_field(DATABASE_NAME_COLUMN));
+        for (position, element) in get_order_by_elements(query).iter().enumerate() {
+            if let OrderByTarget::Column { name, .. } = &element.target {
+                fields.insert(get_order_by_field(position), get_column_field(name));
+            }
+        }
+        fields
+    });
+    models::Query {
+        fields,
+        limit: query
+            .limit
+            .map(|limit| limit.saturating_add(query.offset.unwrap_or(0))),
+        offset: None,
+        ..query.clone()
+    }
+}
+
+// merges the responses of the databases, in the order of the query's order_by, and applies
+// the offset and limit of the query to the merged rows
+pub fn merge_fan_out_responses(
+    query: &models::Query,
+    responses: Vec<Value>,
+) -> Result<Value, ServerError> {
+    let mut rows = vec![];
+    for response in responses {
+        match response.get(0).and_then(|r| r.get("rows")) {
+            Some(Value::Array(database_rows)) => rows.extend(database_rows.iter().cloned()),
+            _ => {
+                return Err(ServerError::Internal(
+                    "unexpected response from a database".into(),
+                ))
+            }
+        }
+    }
+
+    // the sort is stable, so rows that compare equal stay in database name order
+    let elements = get_order_by_elements(query);
+    rows.sort_by(|a, b| compare_rows(a, b, elements));
+
+    let rows: Vec<Value> = rows
+        .into_iter()
+        .skip(query.offset.unwrap_or(0) as usize)
+        .take(query.limit.map_or(usize::MAX, |limit| limit as usize))
+        .map(|mut row| {
+            if let Value::Object(fields) = &mut row {
+                for position in 0..elements.len() {
+                    fields.remove(&get_order_by_field(position));
+                }
+            }
+            row
+        })
+        .collect();
+
+    Ok(json!([{ "rows": rows }]))
+}
+
+fn get_order_by_elements(query: &models::Query) -> &[models::OrderByElement] {
+    match &query.order_by {
+        Some(order_by) => &order_by.elements[..],
+        None => &[],
+    }
+}
+
+fn get_order_by_field(position: usize) -> String {
+    format!("{}{}", ORDER_BY_FIELD_PREFIX, position)
+}
+
+fn get_column_field(column: &str) -> models::Field {
+    models::Field::Column {
+        column: column.into(),
+        arguments: HashMap::new(),
+    }
+}
+
+fn compare_rows(a: &Value, b: &Value, elements: &[models::OrderByElement]) -> Ordering {
+    elements
+        .iter()
+        .enumerate()
+        .map(|(position, element)| {
+            let field = get_order_by_field(position);
+            let ordering = compare_values(&a[&field]["value"], &b[&field]["value"]);
+            match element.order_direction {
+                OrderDirection::Asc => ordering,
+                OrderDirection::Desc => ordering.reverse(),
+            }
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+// nulls sort after every other value, like they do in Postgres: last when ascending and
+// first when descending. Strings are compared by their bytes, which is the order of the C
+// collation the databases sort them with when their rows are merged.
+pub fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Greater,
+        (_, Value::Null) => Ordering::Less,
+        (Value::Number(a), Value::Number(b)) => compare_numbers(a, b),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        _ => a.to_string().cmp(&b.to_string()),
+    }
+}
+
+// integers are compared exactly, as bigints do not all fit in a float, and only other numbers
+// are compared as floats
+fn compare_numbers(a: &Number, b: &Number) -> Ordering {
+    match (a.as_i64(), b.as_i64()) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        // integers that are not an i64 are above i64::MAX
+        (Some(_), None) if b.is_u64() => Ordering::Less,
+        (None, Some(_)) if a.is_u64() => Ordering::Greater,
+        _ => match (a.as_u64(), b.as_u64()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => a
+                .as_f64()
+                .partial_cmp(&b.as_f64())
+                .unwrap_or(Ordering::Equal),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_query(query: Value) -> models::Query {
+        serde_json::from_value(query).expect("the query should be valid")
+    }
+
+    fn get_ordered_query(direction: &str, limit: u32, offset: u32) -> models::Query {
+        get_query(json!({
+            "fields": {
+                "name": { "type": "column", "column": "name", "arguments": {} },
+            },
+            "order_by": {
+                "elements": [{
+                    "order_direction": direction,
+                    "target": { "type": "column", "name": "size", "path": [] },
+                }],
+            },
+            "limit": limit,
+            "offset": offset,
+        }))
+    }
+
+    fn get_response(rows: &[(&str, Value)]) -> Value {
+        let rows: Vec<Value> = rows
+            .iter()
+            .map(|(name, size)| {
+                json!({
+                    "name": { "value": name },
+                    "_order_by_0": { "value": size },
+                })
+            })
+            .collect();
+        json!([{ "rows": rows }])
+    }
+
+    fn get_names(response: &Value) -> Vec<&str> {
+        response[0]["rows"]
+            .as_array()
+            .expect("the response should have rows")
+            .iter()
+            .map(|row| {
+                row["name"]["value"]
+                    .as_str()
+                    .expect("name should be a string")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fan_out_query_adds_the_database_and_order_by_fields() {
+        let query = get_fan_out_query(&get_ordered_query("asc", 10, 5));
+        let fields = query.fields.expect("the query should have fields");
+        assert!(fields.contains_key(DATABASE_NAME_COLUMN));
+        assert!(matches!(
+            fields.get("_order_by_0"),
+            Some(models::Field::Column { column, .. }) if column == "size"
+        ));
+        // every database returns the rows the offset skips as well
+        assert_eq!(query.limit, Some(15));
+        assert_eq!(query.offset, None);
+    }
+
+    #[test]
+    fn merge_orders_pages_and_removes_the_order_by_fields() {
+        let query = get_ordered_query("asc", 2, 1);
+        let merged = merge_fan_out_responses(
+            &query,
+            vec![
+                get_response(&[("a", json!(1)), ("c", json!(3))]),
+                get_response(&[("b", json!(2)), ("d", json!(4))]),
+            ],
+        )
+        .expect("the responses should merge");
+        assert_eq!(get_names(&merged), vec!["b", "c"]);
+        assert!(merged[0]["rows"][0].get("_order_by_0").is_none());
+    }
+
+    #[test]
+    fn merge_sorts_nulls_first_when_descending() {
+        let query = get_ordered_query("desc", 10, 0);
+        let merged = merge_fan_out_responses(
+            &query,
+            vec![
+                get_response(&[("a", json!(1))]),
+                get_response(&[("b", Value::Null), ("c", json!(2))]),
+            ],
+        )
+        .expect("the responses should merge");
+        assert_eq!(get_names(&merged), vec!["b", "c", "a"]);
+    }
+
+    #[test]
+    fn merge_keeps_equal_rows_in_database_order() {
+        let query = get_ordered_query("asc", 10, 0);
+        let merged = merge_fan_out_responses(
+            &query,
+            vec![
+                get_response(&[("a", json!(1))]),
+                get_response(&[("b", json!(1))]),
+            ],
+        )
+        .expect("the responses should merge");
+        assert_eq!(get_names(&merged), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn merge_rejects_unexpected_responses() {
+        let query = get_ordered_query("asc", 10, 0);
+        assert!(merge_fan_out_responses(&query, vec![json!({ "rows": [] })]).is_err());
+    }
+
+    #[test]
+    fn integers_are_compared_exactly() {
+        // both round to the same float
+        let a = json!(9007199254740993_i64);
+        let b = json!(9007199254740992_i64);
+        assert_eq!(compare_values(&a, &b), Ordering::Greater);
+        assert_eq!(
+            compare_values(&json!(u64::MAX), &json!(-1)),
+            Ordering::Greater
+        );
+        assert_eq!(compare_values(&json!(-1), &json!(u64::MAX)), Ordering::Less);
+        assert_eq!(compare_values(&json!(1.5), &json!(1)), Ordering::Greater);
+    }
+
+    #[test]
+    fn strings_are_compared_by_bytes() {
+        assert_eq!(compare_values(&json!("B"), &json!("a")), Ordering::Less);
+        assert_eq!(compare_values(&json!("a"), &json!("ä")), Ordering::Less);
+    }
+}
@@ -0,0 +1,76 @@
+use cc_postgres::configuration;
+use cc_postgres::error::ServerError;
+use cc_postgres::metadata;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+use crate::routes::get_schema::get_schema;
+
+// writes the v3 metadata of the schema the /schema route returns to the output file. The URL of
+// the data source is data_source_url, or DATA_SOURCE_URL. With check, the file is left as it is,
+// and an error is returned when it differs from the generated metadata; without a URL, the
+// metadata is generated with the URL of the file.
+pub fn generate_metadata(
+    data_source_url: Option<&String>,
+    output: &Path,
+    check: bool,
+) -> Result<(), ServerError> {
+    let data_source_url = data_source_url
+        .cloned()
+        .or_else(configuration::get_data_source_url);
+
+    if check {
+        let committed = fs::read_to_string(output).map_err(|err| {
+            ServerError::Internal(format!("could not read {}: {}", output.display(), err))
+        })?;
+        // the files are compared as JSON, so formatting alone does not make the file stale
+        let committed = serde_json::from_str::<Value>(&committed).map_err(|err| {
+            ServerError::Internal(format!("{} is not valid JSON: {}", output.display(), err))
+        })?;
+        let data_source_url = match data_source_url {
+            Some(url) => url,
+            None => metadata::get_data_source_url(&committed)
+                .ok_or_else(|| {
+                    ServerError::BadRequest(format!(
+                        "{} has no data source URL, pass --data-source-url",
+                        output.display()
+                    ))
+                })?
+                .to_string(),
+        };
+        let generated = metadata::get_metadata(&get_schema(), &data_source_url)?;
+        let generated = serde_json::from_str::<Value>(&generated)
+            .map_err(|err| ServerError::Internal(err.to_string()))?;
+        if committed != generated {
+            return Err(ServerError::BadRequest(format!(
+                "{} is stale, run generate-metadata to regenerate it",
+                output.display()
+            )));
+        }
+        println!("{} is up to date", output.display());
+        return Ok(());
+    }
+
+    let data_source_url = data_source_url.ok_or_else(|| {
+        ServerError::BadRequest("pass --data-source-url, or set DATA_SOURCE_URL".into())
+    })?;
+    let metadata = metadata::get_metadata(&get_schema(), &data_source_url)?;
+    fs::write(output, metadata).map_err(|err| {
+        ServerError::Internal(format!("could not write {}: {}", output.display(), err))
+    })?;
+    println!("wrote {}", output.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the committed metadata is the one generate-metadata writes with the URL of the file
+    #[test]
+    fn committed_metadata_is_up_to_date() {
+        generate_metadata(None, Path::new(metadata::DEFAULT_METADATA_PATH), true)
+            .expect("hasura/metadata.json should be up to date");
+    }
+}
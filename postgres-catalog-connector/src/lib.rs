@@ -1,4 +1,14 @@
+pub mod arguments;
+pub mod commands;
 pub mod configuration;
+pub mod data_dictionary;
+pub mod er_diagram;
 pub mod error;
+pub mod fan_out;
+pub mod lint;
+pub mod metadata;
+pub mod rows;
+pub mod schema_diff;
+pub mod snapshot;
 pub mod sql;
 pub mod tables;
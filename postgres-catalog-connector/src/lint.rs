@@ -0,0 +1,582 @@
+use once_cell::sync::OnceCell;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use url::Url;
+
+use crate::configuration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl LintSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintSeverity::Error => "error",
+            LintSeverity::Warning => "warning",
+            LintSeverity::Info => "info",
+        }
+    }
+}
+
+// a schema lint rule. The SQL of a rule selects its findings as object_type, table_schema,
+// table_name, object_name and message, and can use the lint_tables, lint_columns and
+// lint_objects tables of LINT_TABLES_SQL.
+pub struct LintRule {
+    pub id: &'static str,
+    pub severity: LintSeverity,
+    pub description: &'static str,
+    sql: &'static str,
+}
+
+// the tables and columns the rules look at. Tables that belong to an extension are left out, and
+// so are the columns of partitions, since those are the columns of their partitioned table.
+const LINT_TABLES_SQL: &str = "
+lint_tables AS (
+    SELECT
+        c.oid,
+        n.nspname AS table_schema,
+        c.relname AS table_name,
+        c.relkind,
+        c.relispartition
+    FROM pg_class AS c
+    JOIN pg_namespace AS n ON n.oid = c.relnamespace
+    WHERE c.relkind IN ('r', 'p', 'v', 'm', 'f')
+        AND NOT pg_is_other_temp_schema(n.oid)
+        AND NOT EXISTS (
+            SELECT 1
+            FROM pg_depend AS d
+            WHERE d.classid = CAST('pg_class' AS REGCLASS)
+                AND d.objid = c.oid
+                AND d.deptype = 'e'
+        )
+),
+lint_columns AS (
+    SELECT
+        t.oid,
+        t.table_schema,
+        t.table_name,
+        a.attname AS column_name,
+        a.attnum,
+        a.atttypid,
+        a.atttypmod,
+        a.attnotnull
+    FROM lint_tables AS t
+    JOIN pg_attribute AS a ON a.attrelid = t.oid
+    WHERE t.relkind IN ('r', 'p', 'f')
+        AND NOT t.relispartition
+        AND a.attnum > 0
+        AND NOT a.attisdropped
+),
+lint_objects AS (
+    SELECT
+        'table' AS object_type,
+        table_schema,
+        table_name,
+        table_name AS object_name,
+        obj_description(oid, 'pg_class') AS description
+    FROM lint_tables
+    WHERE NOT relispartition
+    UNION ALL
+    SELECT
+        'column',
+        table_schema,
+        table_name,
+        column_name,
+        col_description(oid, attnum)
+    FROM lint_columns
+)";
+
+pub const LINT_RULES: &[LintRule] = &[
+    LintRule {
+        id: "table_without_primary_key",
+        severity: LintSeverity::Warning,
+        description: "Tables without a primary key",
+        sql: "
+SELECT
+    'table' AS object_type,
+    t.table_schema,
+    t.table_name,
+    t.table_name AS object_name,
+    'The table has no primary key.' AS message
+FROM lint_tables AS t
+WHERE t.relkind IN ('r', 'p')
+    AND NOT t.relispartition
+    AND NOT EXISTS (SELECT 1 FROM pg_constraint AS k WHERE k.conrelid = t.oid AND k.contype = 'p')",
+    },
+    LintRule {
+        id: "unindexed_foreign_key",
+        severity: LintSeverity::Warning,
+        description: "Foreign keys that no index starts with the columns of",
+        sql: "
+SELECT
+    'foreign_key' AS object_type,
+    t.table_schema,
+    t.table_name,
+    k.conname AS object_name,
+    'No index starts with the columns of the foreign key, so deleting or updating a referenced row scans the whole table.' AS message
+FROM pg_constraint AS k
+JOIN lint_tables AS t ON t.oid = k.conrelid
+WHERE k.contype = 'f'
+    AND k.conparentid = 0
+    AND NOT EXISTS (
+        SELECT 1
+        FROM pg_index AS i
+        WHERE i.indrelid = k.conrelid
+            AND i.indisvalid
+            AND i.indpred IS NULL
+            AND i.indnkeyatts >= cardinality(k.conkey)
+            AND NOT EXISTS (
+                SELECT 1
+                FROM unnest(k.conkey) AS c(attnum)
+                WHERE coalesce(
+                    array_position(string_to_array(CAST(i.indkey AS TEXT), ' '), CAST(c.attnum AS TEXT)),
+                    cardinality(k.conkey) + 1
+                ) > cardinality(k.conkey)
+            )
+    )",
+    },
+    LintRule {
+        id: "nullable_foreign_key_column",
+        severity: LintSeverity::Info,
+        description: "Nullable columns of foreign keys",
+        sql: "
+SELECT
+    'column' AS object_type,
+    c.table_schema,
+    c.table_name,
+    c.column_name AS object_name,
+    'The column is part of a foreign key but can be null.' AS message
+FROM lint_columns AS c
+WHERE NOT c.attnotnull
+    AND EXISTS (
+        SELECT 1
+        FROM pg_constraint AS k
+        WHERE k.conrelid = c.oid AND k.contype = 'f' AND c.attnum = ANY(k.conkey)
+    )",
+    },
+    LintRule {
+        id: "timestamp_without_time_zone",
+        severity: LintSeverity::Warning,
+        description: "timestamp columns, which do not store a time zone",
+        sql: "
+SELECT
+    'column' AS object_type,
+    c.table_schema,
+    c.table_name,
+    c.column_name AS object_name,
+    'The column is a timestamp without time zone; timestamptz stores an unambiguous point in time.' AS message
+FROM lint_columns AS c
+WHERE c.atttypid = CAST('timestamp' AS REGTYPE)",
+    },
+    LintRule {
+        id: "varchar_length",
+        severity: LintSeverity::Info,
+        description: "varchar(n) columns, where text is usually preferred",
+        sql: "
+SELECT
+    'column' AS object_type,
+    c.table_schema,
+    c.table_name,
+    c.column_name AS object_name,
+    'The column is a ' || format_type(c.atttypid, c.atttypmod) || '; text with a CHECK constraint on its length is as fast and easier to change.' AS message
+FROM lint_columns AS c
+WHERE c.atttypid = CAST('varchar' AS REGTYPE) AND c.atttypmod <> -1",
+    },
+    LintRule {
+        id: "missing_comment",
+        severity: LintSeverity::Info,
+        description: "Tables, views and columns without a comment",
+        sql: "
+SELECT
+    o.object_type,
+    o.table_schema,
+    o.table_name,
+    o.object_name,
+    'The ' || o.object_type || ' has no comment.' AS message
+FROM lint_objects AS o
+WHERE o.description IS NULL OR o.description = ''",
+    },
+    LintRule {
+        id: "identifier_needs_quoting",
+        severity: LintSeverity::Warning,
+        description: "Names with upper case or special characters, which have to be quoted",
+        sql: "
+SELECT
+    o.object_type,
+    o.table_schema,
+    o.table_name,
+    o.object_name,
+    'The name has upper case or special characters, so it has to be quoted as ' || quote_ident(o.object_name) || '.' AS message
+FROM lint_objects AS o
+WHERE quote_ident(o.object_name) <> o.object_name
+    AND NOT EXISTS (SELECT 1 FROM pg_get_keywords() AS k WHERE k.word = o.object_name)",
+    },
+    LintRule {
+        id: "reserved_word_identifier",
+        severity: LintSeverity::Warning,
+        description: "Names that are reserved words",
+        sql: "
+SELECT
+    o.object_type,
+    o.table_schema,
+    o.table_name,
+    o.object_name,
+    'The name is a keyword (' || k.catdesc || '), so it has to be quoted in some places.' AS message
+FROM lint_objects AS o
+JOIN pg_get_keywords() AS k ON k.word = o.object_name
+WHERE k.catcode <> 'U'",
+    },
+];
+
+// the lint configuration, read from the JSON file LINT_CONFIG points to. Rules are configured
+// for every database under "rules", and for the database of a single source of SOURCES under
+// "sources":
+// {"rules": {"missing_comment": {"enabled": false}},
+//  "sources": {"billing": {"rules": {"varchar_length": {"severity": "warning"}}}}}
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintConfiguration {
+    #[serde(default)]
+    pub rules: HashMap<String, LintRuleConfiguration>,
+    #[serde(default)]
+    pub sources: HashMap<String, LintSourceConfiguration>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintSourceConfiguration {
+    #[serde(default)]
+    pub rules: HashMap<String, LintRuleConfiguration>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintRuleConfiguration {
+    pub enabled: Option<bool>,
+    pub severity: Option<LintSeverity>,
+}
+
+impl LintConfiguration {
+    fn from_file(path: &str, sources: &HashMap<String, String>) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))?;
+        LintConfiguration::from_json(&contents, sources).map_err(|err| format!("{}: {}", path, err))
+    }
+
+    // parses a lint configuration, which can only configure the rules there are for the sources
+    // there are
+    fn from_json(contents: &str, sources: &HashMap<String, String>) -> Result<Self, String> {
+        let lint_configuration: LintConfiguration =
+            serde_json::from_str(contents).map_err(|err| err.to_string())?;
+        for (source, source_configuration) in &lint_configuration.sources {
+            match sources.get(source) {
+                Some(url) if get_database_location(url).is_some() => {}
+                Some(_) => return Err(format!("the URL of source {} is not valid", source)),
+                None => return Err(format!("unknown source {}", source)),
+            }
+            check_rule_ids(source_configuration.rules.keys())?;
+        }
+        check_rule_ids(lint_configuration.rules.keys())?;
+        Ok(lint_configuration)
+    }
+}
+
+fn check_rule_ids<'a>(rule_ids: impl Iterator<Item = &'a String>) -> Result<(), String> {
+    for rule_id in rule_ids {
+        if !LINT_RULES.iter().any(|rule| rule.id == rule_id) {
+            return Err(format!("unknown lint rule {}", rule_id));
+        }
+    }
+    Ok(())
+}
+
+// loads the lint configuration once; an invalid configuration stops the server when it starts
+pub fn get_lint_configuration() -> &'static LintConfiguration {
+    static LINT_CONFIGURATION: OnceCell<LintConfiguration> = OnceCell::new();
+    LINT_CONFIGURATION.get_or_init(|| match configuration::get_lint_config_path() {
+        Some(path) => LintConfiguration::from_file(&path, &configuration::get_sources())
+            .unwrap_or_else(|err| panic!("invalid lint configuration {}", err)),
+        None => LintConfiguration::default(),
+    })
+}
+
+// where a database URL points to: the host and port of its server, and the database on it.
+// Without a database in the URL, Postgres connects to the database named after the user.
+#[derive(Debug, PartialEq, Eq)]
+struct DatabaseLocation {
+    host: Option<String>,
+    port: u16,
+    database: String,
+}
+
+fn get_database_location(url: &str) -> Option<DatabaseLocation> {
+    let url = Url::parse(url).ok()?;
+    let database = match url.path().trim_start_matches('/') {
+        "" => url.username(),
+        database => database,
+    };
+    Some(DatabaseLocation {
+        host: url.host_str().map(|host| host.to_string()),
+        port: url.port().unwrap_or(5432),
+        database: database.to_string(),
+    })
+}
+
+// gets the configurations of the sources on the same server as the database URL, by the name of
+// their database. Sources on other servers do not apply to the database, or to the other
+// databases on its server when the query is run against all of them.
+fn get_source_databases<'a>(
+    lint_configuration: &'a LintConfiguration,
+    sources: &HashMap<String, String>,
+    database_url: Option<&str>,
+) -> Vec<(String, &'a LintSourceConfiguration)> {
+    let location = match database_url.and_then(get_database_location) {
+        Some(location) => location,
+        None => return vec![],
+    };
+    let mut databases: Vec<(&String, String, &LintSourceConfiguration)> = lint_configuration
+        .sources
+        .iter()
+        .filter_map(|(source, source_configuration)| {
+            let source_location = sources
+                .get(source)
+                .and_then(|url| get_database_location(url))?;
+            (source_location.host == location.host && source_location.port == location.port)
+                .then_some((source, source_location.database, source_configuration))
+        })
+        .collect();
+    databases.sort_by_key(|(source, _, _)| *source);
+    databases
+        .into_iter()
+        .map(|(_, database, source_configuration)| (database, source_configuration))
+        .collect()
+}
+
+// gets the SQL of the lint_findings table of the database at database_url: the findings of every
+// enabled rule. A rule that is configured for a single source is enabled and given its severity
+// with current_database(), so that the same SQL can run against every database of a server.
+pub fn get_lint_findings_sql(
+    lint_configuration: &LintConfiguration,
+    sources: &HashMap<String, String>,
+    database_url: Option<&str>,
+) -> String {
+    let source_databases = get_source_databases(lint_configuration, sources, database_url);
+    let mut rules_sql: Vec<String> = LINT_RULES
+        .iter()
+        .filter_map(|rule| get_lint_rule_sql(rule, lint_configuration, &source_databases))
+        .collect();
+    if rules_sql.is_empty() {
+        rules_sql.push(
+            "
+SELECT
+    CAST(NULL AS TEXT) AS rule_id,
+    CAST(NULL AS TEXT) AS severity,
+    CAST(NULL AS TEXT) AS object_type,
+    CAST(NULL AS TEXT) AS table_schema,
+    CAST(NULL AS TEXT) AS table_name,
+    CAST(NULL AS TEXT) AS object_name,
+    CAST(NULL AS TEXT) AS message
+WHERE false"
+                .into(),
+        );
+    }
+    format!(
+        "WITH {}\n{}",
+        LINT_TABLES_SQL,
+        rules_sql.join("\nUNION ALL\n")
+    )
+}
+
+fn get_lint_rule_sql(
+    rule: &LintRule,
+    lint_configuration: &LintConfiguration,
+    source_databases: &[(String, &LintSourceConfiguration)],
+) -> Option<String> {
+    let default = lint_configuration.rules.get(rule.id);
+    let enabled = default.and_then(|r| r.enabled).unwrap_or(true);
+    let severity = default.and_then(|r| r.severity).unwrap_or(rule.severity);
+    let databases: Vec<(&String, &LintRuleConfiguration)> = source_databases
+        .iter()
+        .filter_map(|(database, s)| s.rules.get(rule.id).map(|r| (database, r)))
+        .collect();
+
+    // the databases the rule is enabled or disabled in, as opposed to all the others
+    let toggled_databases: Vec<String> = databases
+        .iter()
+        .filter(|(_, r)| matches!(r.enabled, Some(e) if e != enabled))
+        .map(|(database, _)| get_sql_string(database))
+        .collect();
+    let filter = match (enabled, toggled_databases.is_empty()) {
+        (true, true) => String::new(),
+        (false, true) => return None,
+        (true, false) => format!(
+            "\nWHERE current_database() NOT IN ({})",
+            toggled_databases.join(", ")
+        ),
+        (false, false) => format!(
+            "\nWHERE current_database() IN ({})",
+            toggled_databases.join(", ")
+        ),
+    };
+
+    let severity_overrides: Vec<String> = databases
+        .iter()
+        .filter_map(|(database, r)| {
+            r.severity.map(|s| {
+                format!(
+                    " WHEN {} THEN {}",
+                    get_sql_string(database),
+                    get_sql_string(s.as_str())
+                )
+            })
+        })
+        .collect();
+    let severity_sql = if severity_overrides.is_empty() {
+        get_sql_string(severity.as_str())
+    } else {
+        format!(
+            "CASE current_database(){} ELSE {} END",
+            severity_overrides.concat(),
+            get_sql_string(severity.as_str())
+        )
+    };
+
+    Some(format!(
+        "SELECT
+    {} AS rule_id,
+    {} AS severity,
+    f.object_type,
+    f.table_schema,
+    f.table_name,
+    f.object_name,
+    f.message
+FROM ({}
+) AS f{}",
+        get_sql_string(rule.id),
+        severity_sql,
+        rule.sql,
+        filter
+    ))
+}
+
+fn get_sql_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlparser::dialect::PostgreSqlDialect;
+    use sqlparser::parser::Parser;
+
+    fn get_sources() -> HashMap<String, String> {
+        HashMap::from_iter([
+            (
+                "billing".into(),
+                "postgres://app@db.internal/billing".into(),
+            ),
+            (
+                "staging".into(),
+                "postgres://app@staging.internal:6432/billing".into(),
+            ),
+        ])
+    }
+
+    fn get_configuration(contents: &str) -> LintConfiguration {
+        LintConfiguration::from_json(contents, &get_sources())
+            .expect("the lint configuration should be valid")
+    }
+
+    #[test]
+    fn findings_sql_parses() {
+        let sql = get_lint_findings_sql(&LintConfiguration::default(), &get_sources(), None);
+        Parser::parse_sql(&PostgreSqlDialect {}, &sql).expect("the lint SQL should parse");
+        for rule in LINT_RULES {
+            assert!(sql.contains(&format!("'{}' AS rule_id", rule.id)));
+        }
+    }
+
+    #[test]
+    fn disabled_rules_are_left_out() {
+        let lint_configuration =
+            get_configuration(r#"{"rules": {"missing_comment": {"enabled": false}}}"#);
+        let sql = get_lint_findings_sql(&lint_configuration, &get_sources(), None);
+        assert!(!sql.contains("'missing_comment' AS rule_id"));
+        assert!(sql.contains("'table_without_primary_key' AS rule_id"));
+    }
+
+    #[test]
+    fn every_rule_can_be_disabled() {
+        let rules: Vec<String> = LINT_RULES
+            .iter()
+            .map(|rule| format!(r#""{}": {{"enabled": false}}"#, rule.id))
+            .collect();
+        let lint_configuration =
+            get_configuration(&format!(r#"{{"rules": {{{}}}}}"#, rules.join(",")));
+        let sql = get_lint_findings_sql(&lint_configuration, &get_sources(), None);
+        Parser::parse_sql(&PostgreSqlDialect {}, &sql).expect("the lint SQL should parse");
+        assert!(sql.contains("WHERE false"));
+    }
+
+    #[test]
+    fn source_configuration_applies_to_databases_on_its_server() {
+        let lint_configuration = get_configuration(
+            r#"{"sources": {"billing": {"rules": {"varchar_length": {"severity": "error"}}}}}"#,
+        );
+        let sql = get_lint_findings_sql(
+            &lint_configuration,
+            &get_sources(),
+            Some("postgres://other@db.internal:5432/orders"),
+        );
+        assert!(sql.contains("CASE current_database() WHEN 'billing' THEN 'error' ELSE 'info' END"));
+    }
+
+    #[test]
+    fn source_configuration_does_not_apply_to_other_servers() {
+        // the staging source has a database of the same name on another server
+        let lint_configuration = get_configuration(
+            r#"{"sources": {"staging": {"rules": {"missing_comment": {"enabled": false}}}}}"#,
+        );
+        let sql = get_lint_findings_sql(
+            &lint_configuration,
+            &get_sources(),
+            Some("postgres://app@db.internal/billing"),
+        );
+        assert!(!sql.contains("current_database()"));
+        let sql = get_lint_findings_sql(
+            &lint_configuration,
+            &get_sources(),
+            Some("postgres://app@staging.internal:6432/billing"),
+        );
+        assert!(sql.contains("WHERE current_database() NOT IN ('billing')"));
+    }
+
+    #[test]
+    fn unknown_rules_and_sources_are_rejected() {
+        let sources = get_sources();
+        assert!(
+            LintConfiguration::from_json(r#"{"rules": {"no_such_rule": {}}}"#, &sources).is_err()
+        );
+        assert!(
+            LintConfiguration::from_json(r#"{"sources": {"production": {}}}"#, &sources).is_err()
+        );
+        assert!(LintConfiguration::from_json(r#"{"databases": {}}"#, &sources).is_err());
+    }
+
+    #[test]
+    fn database_location_defaults_to_the_user_database_and_port() {
+        assert_eq!(
+            get_database_location("postgres://app@db.internal"),
+            Some(DatabaseLocation {
+                host: Some("db.internal".into()),
+                port: 5432,
+                database: "app".into(),
+            })
+        );
+    }
+}
@@ -1,14 +1,117 @@
-mod configuration;
+mod export;
+mod generate_metadata;
 mod routes;
 use axum;
+use clap::{Arg, ArgAction, Command};
+use std::path::Path;
+use std::str::FromStr;
+
+use cc_postgres::configuration;
+use cc_postgres::data_dictionary::DataDictionaryFormat;
+use cc_postgres::metadata;
 
 #[tokio::main]
 async fn main() {
+    let matches = Command::new("cc_postgres")
+        .about("A data connector for the catalogs of Postgres databases")
+        .subcommand(
+            Command::new("export")
+                .about("Write a data dictionary of a database: a page per schema, with a section per table")
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .value_parser(["markdown", "html"])
+                        .default_value("markdown")
+                        .help("The format of the pages"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .default_value("data_dictionary")
+                        .help("The directory the pages are written to"),
+                )
+                .arg(
+                    Arg::new("database-url")
+                        .long("database-url")
+                        .help("The PG connection URI of the database, or a snapshot: URL; defaults to DEFAULT_DB_URL"),
+                ),
+        )
+        .subcommand(
+            Command::new("generate-metadata")
+                .about("Write the Hasura v3 metadata of the connector's schema")
+                .arg(
+                    Arg::new("data-source-url")
+                        .long("data-source-url")
+                        .help("The URL the engine reaches the connector at; defaults to DATA_SOURCE_URL"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .default_value(metadata::DEFAULT_METADATA_PATH)
+                        .help("The metadata file"),
+                )
+                .arg(
+                    Arg::new("check")
+                        .long("check")
+                        .action(ArgAction::SetTrue)
+                        .help("Fail if the metadata file is stale instead of writing it"),
+                ),
+        )
+        .get_matches();
+
+    if let Some(("export", args)) = matches.subcommand() {
+        let format = args
+            .get_one::<String>("format")
+            .expect("format has a default value");
+        let output = args
+            .get_one::<String>("output")
+            .expect("output has a default value");
+        let result = match DataDictionaryFormat::from_str(format) {
+            Ok(format) => {
+                export::export_data_dictionary(
+                    args.get_one::<String>("database-url"),
+                    format,
+                    Path::new(output),
+                )
+                .await
+            }
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            eprintln!("could not export the data dictionary: {}", err);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(("generate-metadata", args)) = matches.subcommand() {
+        let output = args
+            .get_one::<String>("output")
+            .expect("output has a default value");
+        let check = args.get_flag("check");
+        if let Err(err) = generate_metadata::generate_metadata(
+            args.get_one::<String>("data-source-url"),
+            Path::new(output),
+            check,
+        ) {
+            match check {
+                true => eprintln!("could not check the metadata: {}", err),
+                false => eprintln!("could not generate the metadata: {}", err),
+            }
+            std::process::exit(1);
+        }
+        return;
+    }
+
     // initialize routes
     let router = routes::create_router();
 
     // get and print server address
     let server_config = configuration::get_configuration();
+    // load the lint configuration now, so that an invalid one stops the server from starting
+    cc_postgres::lint::get_lint_configuration();
     let address = format!("0.0.0.0:{}", server_config.port);
     println!("Starting server at {}", address);
 
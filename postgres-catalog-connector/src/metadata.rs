@@ -0,0 +1,449 @@
+use ndc_client::models;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::error::ServerError;
+
+pub const DATA_SOURCE_NAME: &str = "pg_catalog_connector";
+// hasura/metadata.json, wherever the command is run from
+pub const DEFAULT_METADATA_PATH: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../hasura/metadata.json");
+
+// the data connectors and validation settings of the engine, which do not depend on the schema
+const DATA_CONNECTORS: &str = "http://v3-super-connector:4000/deployment/";
+const VALIDATE_CONNECTORS: bool = false;
+
+#[derive(Serialize)]
+struct Metadata {
+    metadata: Vec<MetadataEntry>,
+    data_connectors: BTreeMap<&'static str, &'static str>,
+    validate_connectors: bool,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum MetadataEntry {
+    #[serde(rename_all = "camelCase")]
+    DataSource {
+        source_type: &'static str,
+        source_config: DataSourceConfig,
+        name: &'static str,
+        schema: Value,
+    },
+    ObjectType {
+        name: String,
+        fields: Vec<ObjectTypeField>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Model { name: String, data_type: String },
+    #[serde(rename_all = "camelCase")]
+    ModelSource {
+        model_name: String,
+        data_source: &'static str,
+        model_source: ModelSourceTable,
+        type_sources: BTreeMap<String, TypeSource>,
+    },
+    #[serde(rename_all = "camelCase")]
+    GraphqlType {
+        type_name: String,
+        graphql_type_name: String,
+        graphql_type_kind: &'static str,
+    },
+    #[serde(rename_all = "camelCase")]
+    ModelGraphqlApi {
+        model_name: String,
+        select_uniques: Vec<SelectUnique>,
+    },
+}
+
+#[derive(Serialize)]
+struct DataSourceConfig {
+    url: String,
+}
+
+#[derive(Serialize)]
+struct ObjectTypeField {
+    name: String,
+    #[serde(rename = "type")]
+    field_type: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ModelSourceTable {
+    table_path: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TypeSource {
+    field_sources: BTreeMap<String, FieldSource>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FieldSource {
+    field_source: FieldSourceColumn,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FieldSourceColumn {
+    column_name: String,
+    column_type: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SelectUnique {
+    query_root_field: String,
+    unique_identifier: Vec<String>,
+}
+
+// builds the v3 metadata of the connector from its schema: the data source, and an object type,
+// model, model source, GraphQL type and model GraphQL API for every table. Commands are only part
+// of the data source schema. The metadata is formatted like hasura/metadata.json, with an indent
+// of 4 spaces.
+pub fn get_metadata(
+    schema: &models::SchemaResponse,
+    data_source_url: &str,
+) -> Result<String, ServerError> {
+    let schema_value =
+        serde_json::to_value(schema).map_err(|err| ServerError::Internal(err.to_string()))?;
+
+    let mut object_types = vec![];
+    let mut models = vec![];
+    let mut model_sources = vec![];
+    let mut graphql_types = vec![];
+    let mut model_graphql_apis = vec![];
+    for table in &schema.tables {
+        let object_type = schema.object_types.get(&table.table_type).ok_or_else(|| {
+            ServerError::Internal(format!(
+                "the {} table has an unknown type: {}",
+                table.name, table.table_type
+            ))
+        })?;
+        // fields of object types are relationships, which are not exposed as columns
+        let mut columns = object_type
+            .fields
+            .iter()
+            .filter_map(|(field_name, field)| {
+                get_scalar_type(schema, &field.r#type)
+                    .map(|field_type| (field_name.clone(), field_type))
+            })
+            .collect::<Vec<_>>();
+        columns.sort();
+
+        let model_name = get_graphql_name(&table.name, "_");
+        object_types.push(MetadataEntry::ObjectType {
+            name: table.table_type.clone(),
+            fields: columns
+                .iter()
+                .map(|(name, field_type)| ObjectTypeField {
+                    name: name.clone(),
+                    field_type: field_type.clone(),
+                })
+                .collect(),
+        });
+        models.push(MetadataEntry::Model {
+            name: model_name.clone(),
+            data_type: table.table_type.clone(),
+        });
+        model_sources.push(MetadataEntry::ModelSource {
+            model_name: model_name.clone(),
+            data_source: DATA_SOURCE_NAME,
+            model_source: ModelSourceTable {
+                table_path: table.name.clone(),
+            },
+            type_sources: BTreeMap::from_iter([(
+                table.table_type.clone(),
+                TypeSource {
+                    field_sources: columns
+                        .iter()
+                        .map(|(name, field_type)| {
+                            (
+                                name.clone(),
+                                FieldSource {
+                                    field_source: FieldSourceColumn {
+                                        column_name: name.clone(),
+                                        column_type: get_column_type(field_type),
+                                    },
+                                },
+                            )
+                        })
+                        .collect(),
+                },
+            )]),
+        });
+        graphql_types.push(MetadataEntry::GraphqlType {
+            type_name: table.table_type.clone(),
+            graphql_type_name: get_graphql_name(&table.table_type, "_"),
+            graphql_type_kind: "Object",
+        });
+        let mut uniqueness_constraints = table.uniqueness_constraints.iter().collect::<Vec<_>>();
+        uniqueness_constraints.sort_by_key(|(name, _)| name.as_str());
+        // the schema does not tell which constraint is the primary key, so the ByID field is only
+        // given to the unique identifier of a table that has one; when there are several, each
+        // field is named after its constraint
+        let has_single_identifier = uniqueness_constraints.len() == 1;
+        model_graphql_apis.push(MetadataEntry::ModelGraphqlApi {
+            model_name,
+            select_uniques: uniqueness_constraints
+                .iter()
+                .map(|(name, constraint)| SelectUnique {
+                    query_root_field: match has_single_identifier {
+                        true => format!("{}ByID", get_graphql_name(&table.table_type, "")),
+                        false => format!("{}By{}", get_graphql_name(&table.table_type, ""), name),
+                    },
+                    unique_identifier: constraint.unique_columns.clone(),
+                })
+                .collect(),
+        });
+    }
+
+    let mut metadata = vec![MetadataEntry::DataSource {
+        source_type: "dataConnector",
+        source_config: DataSourceConfig {
+            url: data_source_url.into(),
+        },
+        name: DATA_SOURCE_NAME,
+        schema: schema_value,
+    }];
+    metadata.extend(object_types);
+    metadata.extend(models);
+    metadata.extend(model_sources);
+    metadata.extend(graphql_types);
+    metadata.extend(model_graphql_apis);
+
+    let metadata = Metadata {
+        metadata,
+        data_connectors: BTreeMap::from_iter([("postgres", DATA_CONNECTORS)]),
+        validate_connectors: VALIDATE_CONNECTORS,
+    };
+
+    let mut text = vec![];
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut text, formatter);
+    metadata
+        .serialize(&mut serializer)
+        .map_err(|err| ServerError::Internal(err.to_string()))?;
+    text.push(b'\n');
+    String::from_utf8(text).map_err(|err| ServerError::Internal(err.to_string()))
+}
+
+// the metadata type of a field, like String or [String], or None if it is not a scalar.
+// Nullability is not part of the metadata types.
+fn get_scalar_type(schema: &models::SchemaResponse, field_type: &models::Type) -> Option<String> {
+    match field_type {
+        models::Type::Named { name } => {
+            schema.scalar_types.contains_key(name).then(|| name.clone())
+        }
+        models::Type::Nullable { underlying_type } => get_scalar_type(schema, underlying_type),
+        models::Type::Array { element_type } => {
+            get_scalar_type(schema, element_type).map(|element_type| format!("[{}]", element_type))
+        }
+    }
+}
+
+// the column type of a metadata type, which is the type of its elements for arrays
+fn get_column_type(field_type: &str) -> String {
+    field_type
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .into()
+}
+
+// capitalizes every word of a snake case name, like foreign_keys to Foreign_Keys
+fn get_graphql_name(name: &str, separator: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut characters = word.chars();
+            match characters.next() {
+                Some(first) => first.to_uppercase().chain(characters).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+// gets the URL of the data source of generated metadata, which is the URL the engine reaches the
+// connector at
+pub fn get_data_source_url(metadata: &Value) -> Option<&str> {
+    metadata["metadata"]
+        .as_array()?
+        .iter()
+        .find(|entry| entry["kind"] == "dataSource" && entry["name"] == DATA_SOURCE_NAME)?
+        ["sourceConfig"]["url"]
+        .as_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    // a table of shelves, whose type has a scalar, a nullable, an array and an object field
+    fn get_test_schema() -> models::SchemaResponse {
+        let scalar_type = json!({
+            "aggregate_functions": {},
+            "comparison_operators": {},
+            "update_operators": {},
+        });
+        serde_json::from_value(json!({
+            "scalar_types": { "String": scalar_type, "Int": scalar_type },
+            "object_types": {
+                "book_shelf": {
+                    "description": null,
+                    "fields": {
+                        "shelf_id": {
+                            "description": null,
+                            "arguments": {},
+                            "type": { "type": "named", "name": "Int" },
+                        },
+                        "label": {
+                            "description": null,
+                            "arguments": {},
+                            "type": {
+                                "type": "nullable",
+                                "underlying_type": { "type": "named", "name": "String" },
+                            },
+                        },
+                        "tags": {
+                            "description": null,
+                            "arguments": {},
+                            "type": {
+                                "type": "array",
+                                "element_type": { "type": "named", "name": "String" },
+                            },
+                        },
+                        "location": {
+                            "description": null,
+                            "arguments": {},
+                            "type": { "type": "named", "name": "book_shelf_location" },
+                        },
+                    },
+                },
+                "book_shelf_location": { "description": null, "fields": {} },
+            },
+            "tables": [{
+                "name": "book_shelves",
+                "description": null,
+                "arguments": {},
+                "type": "book_shelf",
+                "insertable_columns": null,
+                "updatable_columns": null,
+                "deletable": false,
+                "uniqueness_constraints": {
+                    "ShelfId": { "unique_columns": ["shelf_id"] },
+                    "Label": { "unique_columns": ["label"] },
+                },
+                "foreign_keys": {},
+            }],
+            "commands": [],
+        }))
+        .expect("the schema should be valid")
+    }
+
+    fn get_entries(metadata: &Value, kind: &str) -> Vec<Value> {
+        metadata["metadata"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|entry| entry["kind"] == kind)
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn metadata_has_the_data_source_and_the_entries_of_every_table() {
+        let schema = get_test_schema();
+        let text = get_metadata(&schema, "http://connector:5000").unwrap();
+        assert!(text.starts_with("{\n    \"metadata\": [\n"));
+        assert!(text.ends_with("}\n"));
+        let metadata: Value = serde_json::from_str(&text).unwrap();
+
+        let data_sources = get_entries(&metadata, "dataSource");
+        assert_eq!(data_sources.len(), 1);
+        assert_eq!(
+            data_sources[0]["sourceConfig"]["url"],
+            "http://connector:5000"
+        );
+        assert_eq!(
+            get_data_source_url(&metadata),
+            Some("http://connector:5000")
+        );
+        assert_eq!(
+            data_sources[0]["schema"],
+            serde_json::to_value(&schema).unwrap()
+        );
+        assert_eq!(metadata["data_connectors"]["postgres"], DATA_CONNECTORS);
+        assert_eq!(metadata["validate_connectors"], VALIDATE_CONNECTORS);
+
+        // the object field is a relationship, so it is not a column
+        assert_eq!(
+            get_entries(&metadata, "objectType"),
+            vec![json!({
+                "kind": "objectType",
+                "name": "book_shelf",
+                "fields": [
+                    { "name": "label", "type": "String" },
+                    { "name": "shelf_id", "type": "Int" },
+                    { "name": "tags", "type": "[String]" },
+                ],
+            })]
+        );
+        assert_eq!(
+            get_entries(&metadata, "model"),
+            vec![json!({ "kind": "model", "name": "Book_Shelves", "dataType": "book_shelf" })]
+        );
+        let model_sources = get_entries(&metadata, "modelSource");
+        assert_eq!(model_sources[0]["modelSource"]["tablePath"], "book_shelves");
+        assert_eq!(
+            model_sources[0]["typeSources"]["book_shelf"]["fieldSources"]["tags"],
+            json!({ "fieldSource": { "columnName": "tags", "columnType": "String" } })
+        );
+        assert_eq!(
+            get_entries(&metadata, "graphqlType")[0]["graphqlTypeName"],
+            "Book_Shelf"
+        );
+    }
+
+    #[test]
+    fn only_a_single_uniqueness_constraint_gets_the_by_id_field() {
+        let mut schema = get_test_schema();
+        let text = get_metadata(&schema, "http://connector:5000").unwrap();
+        let metadata: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            get_entries(&metadata, "modelGraphqlApi")[0]["selectUniques"],
+            json!([
+                { "queryRootField": "BookShelfByLabel", "uniqueIdentifier": ["label"] },
+                { "queryRootField": "BookShelfByShelfId", "uniqueIdentifier": ["shelf_id"] },
+            ])
+        );
+
+        schema.tables[0].uniqueness_constraints.remove("Label");
+        let text = get_metadata(&schema, "http://connector:5000").unwrap();
+        let metadata: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            get_entries(&metadata, "modelGraphqlApi")[0]["selectUniques"],
+            json!([{ "queryRootField": "BookShelfByID", "uniqueIdentifier": ["shelf_id"] }])
+        );
+    }
+
+    #[test]
+    fn tables_of_unknown_types_are_rejected() {
+        let mut schema = get_test_schema();
+        schema.tables[0].table_type = "shelf".into();
+        assert!(get_metadata(&schema, "http://connector:5000").is_err());
+    }
+
+    #[test]
+    fn graphql_names_capitalize_every_word() {
+        assert_eq!(get_graphql_name("foreign_keys", "_"), "Foreign_Keys");
+        assert_eq!(get_graphql_name("foreign_key", ""), "ForeignKey");
+        assert_eq!(get_graphql_name("pg_stat__io", "_"), "Pg_Stat__Io");
+        assert_eq!(get_column_type("[[String]]"), "String");
+    }
+}
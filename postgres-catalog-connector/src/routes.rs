@@ -2,8 +2,8 @@ use axum::{routing::get, routing::post, Router};
 
 mod get_capabilities;
 mod get_healthz;
-mod get_schema;
-mod post_query;
+pub(crate) mod get_schema;
+pub(crate) mod post_query;
 mod post_query_explain;
 
 pub fn create_router() -> Router {
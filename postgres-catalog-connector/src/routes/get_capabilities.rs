@@ -10,14 +10,14 @@ pub async fn handler() -> Json<CapabilitiesResponse> {
     Json(CapabilitiesResponse {
         versions: "^1.0.0".into(),
         capabilities: Capabilities {
-            explain: Some(empty.clone()),
+            explain: Some(empty),
             query: Some(QueryCapabilities {
                 foreach: None,
                 order_by_aggregate: None,
                 relation_comparisons: None,
             }),
             mutations: None,
-            relationships: Some(empty),
+            relationships: None,
         },
     })
 }
@@ -9,20 +9,34 @@ pub const ROUTENAME: &str = "/schema";
 pub async fn handler() -> Json<models::SchemaResponse> {
     println!("received schema request");
 
+    Json(get_schema())
+}
+
+pub(crate) fn get_schema() -> models::SchemaResponse {
     // scalar types
     let scalar_types = HashMap::from_iter([
         (
             "String".into(),
             models::ScalarType {
                 aggregate_functions: HashMap::new(),
-                comparison_operators: HashMap::from_iter([(
-                    "like".into(),
-                    models::ComparisonOperatorDefinition {
-                        argument_type: models::Type::Named {
-                            name: "String".into(),
+                comparison_operators: HashMap::from_iter([
+                    (
+                        "like".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "String".into(),
+                            },
                         },
-                    },
-                )]),
+                    ),
+                    (
+                        "ilike".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "String".into(),
+                            },
+                        },
+                    ),
+                ]),
                 update_operators: HashMap::new(),
             },
         ),
@@ -51,7 +65,213 @@ pub async fn handler() -> Json<models::SchemaResponse> {
                         },
                     ),
                 ]),
-                comparison_operators: HashMap::from_iter([]),
+                comparison_operators: HashMap::from_iter([
+                    (
+                        "gt".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named { name: "Int".into() },
+                        },
+                    ),
+                    (
+                        "gte".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named { name: "Int".into() },
+                        },
+                    ),
+                    (
+                        "lt".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named { name: "Int".into() },
+                        },
+                    ),
+                    (
+                        "lte".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named { name: "Int".into() },
+                        },
+                    ),
+                ]),
+                update_operators: HashMap::new(),
+            },
+        ),
+        (
+            "Boolean".into(),
+            models::ScalarType {
+                aggregate_functions: HashMap::new(),
+                comparison_operators: HashMap::new(),
+                update_operators: HashMap::new(),
+            },
+        ),
+        (
+            "JSON".into(),
+            models::ScalarType {
+                aggregate_functions: HashMap::new(),
+                comparison_operators: HashMap::new(),
+                update_operators: HashMap::new(),
+            },
+        ),
+        (
+            "BigInt".into(),
+            models::ScalarType {
+                aggregate_functions: HashMap::from_iter([
+                    (
+                        "max".into(),
+                        models::AggregateFunctionDefinition {
+                            result_type: models::Type::Nullable {
+                                underlying_type: Box::new(models::Type::Named {
+                                    name: "BigInt".into(),
+                                }),
+                            },
+                        },
+                    ),
+                    (
+                        "min".into(),
+                        models::AggregateFunctionDefinition {
+                            result_type: models::Type::Nullable {
+                                underlying_type: Box::new(models::Type::Named {
+                                    name: "BigInt".into(),
+                                }),
+                            },
+                        },
+                    ),
+                ]),
+                comparison_operators: HashMap::from_iter([
+                    (
+                        "gt".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "BigInt".into(),
+                            },
+                        },
+                    ),
+                    (
+                        "gte".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "BigInt".into(),
+                            },
+                        },
+                    ),
+                    (
+                        "lt".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "BigInt".into(),
+                            },
+                        },
+                    ),
+                    (
+                        "lte".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "BigInt".into(),
+                            },
+                        },
+                    ),
+                ]),
+                update_operators: HashMap::new(),
+            },
+        ),
+        (
+            "Timestamp".into(),
+            models::ScalarType {
+                aggregate_functions: HashMap::new(),
+                comparison_operators: HashMap::from_iter([
+                    (
+                        "gt".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "Timestamp".into(),
+                            },
+                        },
+                    ),
+                    (
+                        "gte".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "Timestamp".into(),
+                            },
+                        },
+                    ),
+                    (
+                        "lt".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "Timestamp".into(),
+                            },
+                        },
+                    ),
+                    (
+                        "lte".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "Timestamp".into(),
+                            },
+                        },
+                    ),
+                ]),
+                update_operators: HashMap::new(),
+            },
+        ),
+        (
+            "Float".into(),
+            models::ScalarType {
+                aggregate_functions: HashMap::from_iter([
+                    (
+                        "max".into(),
+                        models::AggregateFunctionDefinition {
+                            result_type: models::Type::Nullable {
+                                underlying_type: Box::new(models::Type::Named {
+                                    name: "Float".into(),
+                                }),
+                            },
+                        },
+                    ),
+                    (
+                        "min".into(),
+                        models::AggregateFunctionDefinition {
+                            result_type: models::Type::Nullable {
+                                underlying_type: Box::new(models::Type::Named {
+                                    name: "Float".into(),
+                                }),
+                            },
+                        },
+                    ),
+                ]),
+                comparison_operators: HashMap::from_iter([
+                    (
+                        "gt".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "Float".into(),
+                            },
+                        },
+                    ),
+                    (
+                        "gte".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "Float".into(),
+                            },
+                        },
+                    ),
+                    (
+                        "lt".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "Float".into(),
+                            },
+                        },
+                    ),
+                    (
+                        "lte".into(),
+                        models::ComparisonOperatorDefinition {
+                            argument_type: models::Type::Named {
+                                name: "Float".into(),
+                            },
+                        },
+                    ),
+                ]),
                 update_operators: HashMap::new(),
             },
         ),
@@ -91,9 +311,9 @@ pub async fn handler() -> Json<models::SchemaResponse> {
                 },
             ),
             (
-                "comment".into(),
+                "data_type".into(),
                 models::ObjectField {
-                    description: Some("Comment of the table column".into()),
+                    description: Some("Data type of the column".into()),
                     arguments: HashMap::new(),
                     r#type: models::Type::Named {
                         name: "String".into(),
@@ -101,139 +321,157 @@ pub async fn handler() -> Json<models::SchemaResponse> {
                 },
             ),
             (
-                "table".into(),
+                "is_nullable".into(),
                 models::ObjectField {
-                    description: Some("Comment of the table column".into()),
+                    description: Some("Whether the column accepts NULL values".into()),
                     arguments: HashMap::new(),
                     r#type: models::Type::Named {
-                        name: "table".into(),
+                        name: "Boolean".into(),
                     },
                 },
             ),
-        ]),
-    };
-
-    let table_type = models::ObjectType {
-        description: Some("Postgres table definition".into()),
-        fields: HashMap::from_iter([
             (
-                "table_name".into(),
+                "column_default".into(),
                 models::ObjectField {
-                    description: Some("Name of the Postgres table".into()),
+                    description: Some("Default expression of the column".into()),
                     arguments: HashMap::new(),
-                    r#type: models::Type::Named {
-                        name: "String".into(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
                     },
                 },
             ),
             (
-                "table_schema".into(),
+                "ordinal_position".into(),
                 models::ObjectField {
-                    description: Some("Name of the schema of the Postgres table".into()),
+                    description: Some("Position of the column in the table, starting at 1".into()),
                     arguments: HashMap::new(),
-                    r#type: models::Type::Named {
-                        name: "String".into(),
+                    r#type: models::Type::Named { name: "Int".into() },
+                },
+            ),
+            (
+                "character_maximum_length".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Declared maximum length of character and bit string columns".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named { name: "Int".into() }),
                     },
                 },
             ),
             (
-                "comment".into(),
+                "numeric_precision".into(),
                 models::ObjectField {
-                    description: Some("Name of the Postgres table".into()),
+                    description: Some("Precision of numeric columns".into()),
                     arguments: HashMap::new(),
                     r#type: models::Type::Nullable {
-                        underlying_type: Box::new(models::Type::Named {
-                            name: "String".into(),
-                        }),
+                        underlying_type: Box::new(models::Type::Named { name: "Int".into() }),
                     },
                 },
             ),
             (
-                "columns".into(),
+                "numeric_scale".into(),
                 models::ObjectField {
-                    description: Some("The article's author ID".into()),
+                    description: Some("Scale of exact numeric columns".into()),
                     arguments: HashMap::new(),
-                    r#type: models::Type::Array {
-                        element_type: Box::new(models::Type::Named {
-                            name: "column".into(),
-                        }),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named { name: "Int".into() }),
                     },
                 },
             ),
-        ]),
-    };
-
-    let foreign_key_type = models::ObjectType {
-        description: Some("Postgres foreign keys definition".into()),
-        fields: HashMap::from_iter([
             (
-                "schema_from".into(),
+                "is_identity".into(),
                 models::ObjectField {
-                    description: Some(
-                        "Name of the schema from which the foreign key exists".into(),
-                    ),
+                    description: Some("Whether the column is an identity column".into()),
                     arguments: HashMap::new(),
                     r#type: models::Type::Named {
-                        name: "String".into(),
+                        name: "Boolean".into(),
                     },
                 },
             ),
             (
-                "table_from".into(),
+                "identity_generation".into(),
                 models::ObjectField {
-                    description: Some("Name of the table from which the foreign key exists".into()),
+                    description: Some("ALWAYS or BY DEFAULT for identity columns".into()),
                     arguments: HashMap::new(),
-                    r#type: models::Type::Named {
-                        name: "String".into(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
                     },
                 },
             ),
             (
-                "column_mapping".into(),
+                "is_generated".into(),
                 models::ObjectField {
-                    description: Some("Mapping of the columns with the foreign key".into()),
+                    description: Some("Whether the column is a generated column".into()),
                     arguments: HashMap::new(),
                     r#type: models::Type::Named {
-                        // TODO: This works as of now, but we should update this type to be something more suited,
-                        // like jsonb
-                        name: "String".into(),
+                        name: "Boolean".into(),
                     },
                 },
             ),
             (
-                "schema_to".into(),
+                "generation_expression".into(),
                 models::ObjectField {
-                    description: Some("Name of the schema to which the foreign key exists".into()),
+                    description: Some("Generation expression of generated columns".into()),
                     arguments: HashMap::new(),
-                    r#type: models::Type::Named {
-                        name: "String".into(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
                     },
                 },
             ),
             (
-                "table_to".into(),
+                "collation_name".into(),
                 models::ObjectField {
-                    description: Some("Name of the table to which the foreign key exists".into()),
+                    description: Some(
+                        "Collation of the column, if it is not the default one".into(),
+                    ),
                     arguments: HashMap::new(),
-                    r#type: models::Type::Named {
-                        name: "String".into(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
                     },
                 },
             ),
             (
-                "fkey_name".into(),
+                "comment".into(),
                 models::ObjectField {
-                    description: Some("Name of the foreign key constraint".into()),
+                    description: Some("Comment of the table column".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "table".into(),
+                models::ObjectField {
+                    description: Some("Comment of the table column".into()),
                     arguments: HashMap::new(),
                     r#type: models::Type::Named {
-                        name: "String".into(),
+                        name: "table".into(),
                     },
                 },
             ),
+        ]),
+    };
+
+    let table_type = models::ObjectType {
+        description: Some("Postgres table definition".into()),
+        fields: HashMap::from_iter([
             (
-                "on_update".into(),
+                "table_name".into(),
                 models::ObjectField {
-                    description: Some("On update clause".into()),
+                    description: Some("Name of the Postgres table".into()),
                     arguments: HashMap::new(),
                     r#type: models::Type::Named {
                         name: "String".into(),
@@ -241,112 +479,4622 @@ pub async fn handler() -> Json<models::SchemaResponse> {
                 },
             ),
             (
-                "on_delete".into(),
+                "table_schema".into(),
                 models::ObjectField {
-                    description: Some("On delete clause".into()),
+                    description: Some("Name of the schema of the Postgres table".into()),
                     arguments: HashMap::new(),
                     r#type: models::Type::Named {
                         name: "String".into(),
                     },
                 },
             ),
+            (
+                "table_type".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Type of the table: BASE TABLE, VIEW, FOREIGN or LOCAL TEMPORARY".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "is_foreign".into(),
+                models::ObjectField {
+                    description: Some("Whether the table is a foreign table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "owner".into(),
+                models::ObjectField {
+                    description: Some("Name of the role owning the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "rls_enabled".into(),
+                models::ObjectField {
+                    description: Some("Whether row-level security is enabled on the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "rls_forced".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether row-level security also applies to the owner of the table".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "is_partitioned".into(),
+                models::ObjectField {
+                    description: Some("Whether the table is a partitioned table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "is_partition".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the table is a partition of a partitioned table".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "partition_parent_schema".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema of the parent of the partition".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "partition_parent_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the parent of the partition".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "partition_bound".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Partition bound of the partition, e.g. FOR VALUES FROM (1) TO (10)".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "partition_key".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Partition key of the partitioned table, e.g. RANGE (created_at)".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "partition_strategy".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Partitioning strategy of the partitioned table: range, list or hash".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "partition_depth".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Depth of the partition in its partition tree, 0 for tables that are not a partition".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+            (
+                "comment".into(),
+                models::ObjectField {
+                    description: Some("Comment of the Postgres table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "is_published".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the table is included in at least one publication".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let foreign_key_type = models::ObjectType {
+        description: Some("Postgres foreign keys definition".into()),
+        fields: HashMap::from_iter([
+            (
+                "schema_from".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Name of the schema from which the foreign key exists".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_from".into(),
+                models::ObjectField {
+                    description: Some("Name of the table from which the foreign key exists".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "column_mapping".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Ordered list of {\"from\", \"to\"} column pairs of the foreign key".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Array {
+                        element_type: Box::new(models::Type::Named {
+                            name: "JSON".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "schema_to".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema to which the foreign key exists".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_to".into(),
+                models::ObjectField {
+                    description: Some("Name of the table to which the foreign key exists".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "fkey_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the foreign key constraint".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "on_update".into(),
+                models::ObjectField {
+                    description: Some("On update clause".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "on_delete".into(),
+                models::ObjectField {
+                    description: Some("On delete clause".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let table_stats_type = models::ObjectType {
+        description: Some("Postgres table statistics".into()),
+        fields: HashMap::from_iter([
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema of the Postgres table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the Postgres table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "row_estimate".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Estimated number of rows (reltuples), -1 if the table was never analyzed".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "BigInt".into(),
+                    },
+                },
+            ),
+            (
+                "total_size".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Total disk space used by the table, its indexes and TOAST data, in bytes".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "BigInt".into(),
+                    },
+                },
+            ),
+            (
+                "table_size".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Disk space used by the table and its TOAST data, excluding indexes, in bytes".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "BigInt".into(),
+                    },
+                },
+            ),
+            (
+                "indexes_size".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Disk space used by the indexes of the table, in bytes".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "BigInt".into(),
+                    },
+                },
+            ),
+            (
+                "toast_size".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Disk space used by the TOAST table of the table, in bytes".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "BigInt".into(),
+                    },
+                },
+            ),
+            (
+                "live_tuples".into(),
+                models::ObjectField {
+                    description: Some("Estimated number of live rows".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "dead_tuples".into(),
+                models::ObjectField {
+                    description: Some("Estimated number of dead rows".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "last_vacuum".into(),
+                models::ObjectField {
+                    description: Some("Last time the table was manually vacuumed".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "last_autovacuum".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Last time the table was vacuumed by the autovacuum daemon".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "last_analyze".into(),
+                models::ObjectField {
+                    description: Some("Last time the table was manually analyzed".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "last_autoanalyze".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Last time the table was analyzed by the autovacuum daemon".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "seq_scan".into(),
+                models::ObjectField {
+                    description: Some("Number of sequential scans initiated on the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "idx_scan".into(),
+                models::ObjectField {
+                    description: Some("Number of index scans initiated on the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let column_stats_type = models::ObjectType {
+        description: Some("Postgres column statistics gathered by ANALYZE".into()),
+        fields: HashMap::from_iter([
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema of the Postgres table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the Postgres table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "column_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the table column".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "inherited".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the statistics include the child tables of an inheritance parent".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "null_frac".into(),
+                models::ObjectField {
+                    description: Some("Fraction of the column values that are null".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Float".into(),
+                    },
+                },
+            ),
+            (
+                "n_distinct".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Estimated number of distinct values, or the negated fraction of the row count if negative".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Float".into(),
+                    },
+                },
+            ),
+            (
+                "avg_width".into(),
+                models::ObjectField {
+                    description: Some("Average width in bytes of the column values".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+            (
+                "most_common_vals".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Most common values of the column, in their text form".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Array {
+                            element_type: Box::new(models::Type::Named {
+                                name: "String".into(),
+                            }),
+                        }),
+                    },
+                },
+            ),
+            (
+                "most_common_freqs".into(),
+                models::ObjectField {
+                    description: Some("Frequencies of the most common values".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Array {
+                            element_type: Box::new(models::Type::Named {
+                                name: "Float".into(),
+                            }),
+                        }),
+                    },
+                },
+            ),
+            (
+                "histogram_bounds".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Values that divide the column values into groups of about the same size".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Array {
+                            element_type: Box::new(models::Type::Named {
+                                name: "String".into(),
+                            }),
+                        }),
+                    },
+                },
+            ),
+            (
+                "correlation".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Correlation between the physical row order and the logical order of the column values".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Float".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let sequence_type = models::ObjectType {
+        description: Some("Postgres sequence definition".into()),
+        fields: HashMap::from_iter([
+            (
+                "sequence_schema".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema of the sequence".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "sequence_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the sequence".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "data_type".into(),
+                models::ObjectField {
+                    description: Some("Data type of the sequence".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "start_value".into(),
+                models::ObjectField {
+                    description: Some("Start value of the sequence".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "BigInt".into(),
+                    },
+                },
+            ),
+            (
+                "increment_by".into(),
+                models::ObjectField {
+                    description: Some("Increment of the sequence".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "BigInt".into(),
+                    },
+                },
+            ),
+            (
+                "min_value".into(),
+                models::ObjectField {
+                    description: Some("Minimum value of the sequence".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "BigInt".into(),
+                    },
+                },
+            ),
+            (
+                "max_value".into(),
+                models::ObjectField {
+                    description: Some("Maximum value of the sequence".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "BigInt".into(),
+                    },
+                },
+            ),
+            (
+                "cycle".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the sequence wraps around when it reaches its limit".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "last_value".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Last value returned by the sequence, null if it was never used or cannot be read".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema of the table owning the sequence".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the table owning the sequence".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "column_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the column owning the sequence".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "percent_used".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Percentage of the range of the sequence that has been used. Filter with gte to find the sequences at risk of overflowing".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Float".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let extension_type = models::ObjectType {
+        description: Some("Postgres extension".into()),
+        fields: HashMap::from_iter([
+            (
+                "extension_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the extension".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "installed".into(),
+                models::ObjectField {
+                    description: Some("Whether the extension is installed in the database".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "installed_version".into(),
+                models::ObjectField {
+                    description: Some("Installed version of the extension".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "default_version".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Version of the extension that CREATE EXTENSION installs by default".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "extension_schema".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Schema that the objects of the installed extension are in".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "relocatable".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the installed extension can be moved to another schema".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Boolean".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "comment".into(),
+                models::ObjectField {
+                    description: Some("Comment of the extension".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let foreign_server_type = models::ObjectType {
+        description: Some("Postgres foreign server definition".into()),
+        fields: HashMap::from_iter([
+            (
+                "server_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the foreign server".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "foreign_data_wrapper".into(),
+                models::ObjectField {
+                    description: Some("Name of the foreign data wrapper of the server".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "server_type".into(),
+                models::ObjectField {
+                    description: Some("Type of the server".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "server_version".into(),
+                models::ObjectField {
+                    description: Some("Version of the server".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "owner".into(),
+                models::ObjectField {
+                    description: Some("Owner of the foreign server".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "options".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Options of the foreign server, as an object of option names to values"
+                            .into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "JSON".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let user_mapping_type = models::ObjectType {
+        description: Some("Postgres user mapping of a foreign server".into()),
+        fields: HashMap::from_iter([
+            (
+                "server_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the foreign server".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "user_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the mapped user, or PUBLIC".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "option_names".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Names of the user mapping options. Values are left out since they usually hold credentials".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Array {
+                            element_type: Box::new(models::Type::Named {
+                                name: "String".into(),
+                            }),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let foreign_table_type = models::ObjectType {
+        description: Some("Postgres foreign table definition".into()),
+        fields: HashMap::from_iter([
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema of the foreign table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the foreign table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "server_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the foreign server of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "options".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Options of the foreign table, as an object of option names to values"
+                            .into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "JSON".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let role_type = models::ObjectType {
+        description: Some("Postgres role definition".into()),
+        fields: HashMap::from_iter([
+            (
+                "role_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the role".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "is_superuser".into(),
+                models::ObjectField {
+                    description: Some("Whether the role is a superuser".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "can_login".into(),
+                models::ObjectField {
+                    description: Some("Whether the role can log in".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "inherits".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the role inherits the privileges of the roles it is a member of"
+                            .into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "can_create_role".into(),
+                models::ObjectField {
+                    description: Some("Whether the role can create other roles".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "can_create_db".into(),
+                models::ObjectField {
+                    description: Some("Whether the role can create databases".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "is_replication".into(),
+                models::ObjectField {
+                    description: Some("Whether the role can start streaming replication".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "bypass_rls".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the role bypasses row-level security policies".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "connection_limit".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Maximum number of concurrent connections of the role, -1 for no limit"
+                            .into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named { name: "Int".into() },
+                },
+            ),
+            (
+                "valid_until".into(),
+                models::ObjectField {
+                    description: Some("Time after which the password of the role expires".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "member_of".into(),
+                models::ObjectField {
+                    description: Some("Names of the roles that the role is a member of".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Array {
+                        element_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let table_privilege_type = models::ObjectType {
+        description: Some("Postgres privilege granted on a table or column".into()),
+        fields: HashMap::from_iter([
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema of the Postgres table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the Postgres table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "column_name".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Name of the column for column privileges, null for table privileges".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "grantor".into(),
+                models::ObjectField {
+                    description: Some("Name of the role that granted the privilege".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "grantee".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Name of the role that the privilege is granted to, or PUBLIC".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "privilege_type".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Privilege, e.g. SELECT, INSERT, UPDATE, DELETE, TRUNCATE, REFERENCES or TRIGGER".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "is_grantable".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the grantee can grant the privilege to others".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let policy_type = models::ObjectType {
+        description: Some("Postgres row-level security policy".into()),
+        fields: HashMap::from_iter([
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema of the Postgres table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the Postgres table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "policy_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the policy".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "is_permissive".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the policy is permissive (combined with OR) rather than restrictive (combined with AND)".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "command".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Command that the policy applies to: ALL, SELECT, INSERT, UPDATE or DELETE".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "roles".into(),
+                models::ObjectField {
+                    description: Some("Roles that the policy applies to".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Array {
+                        element_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "using_expression".into(),
+                models::ObjectField {
+                    description: Some(
+                        "USING expression of the policy, checked against existing rows".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "with_check_expression".into(),
+                models::ObjectField {
+                    description: Some(
+                        "WITH CHECK expression of the policy, checked against new rows".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let table_inheritance_type = models::ObjectType {
+        description: Some("Postgres inheritance parent of a table".into()),
+        fields: HashMap::from_iter([
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema of the child table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the child table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "parent_schema".into(),
+                models::ObjectField {
+                    description: Some("Name of the schema of the parent table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "parent_name".into(),
+                models::ObjectField {
+                    description: Some("Name of the parent table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "position".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Position of the parent in the list of parents of the child table, starting at 1".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+            (
+                "is_partition".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the child table is a partition of the parent table".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let dependency_type = models::ObjectType {
+        description: Some("A dependency between two Postgres objects".into()),
+        fields: HashMap::from_iter([
+            (
+                "referencing_type".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The kind of the referencing object, like table, view, table column, function or trigger".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "referencing_schema".into(),
+                models::ObjectField {
+                    description: Some("The schema of the referencing object".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "referencing_name".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The name of the referencing object; the table name for columns".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "referencing_column".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The column name, when the referencing object is a column".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "referencing_identity".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The qualified name of the referencing object, unique among objects of its type".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "referenced_type".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The kind of the referenced object, like table, view, table column, function or trigger".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "referenced_schema".into(),
+                models::ObjectField {
+                    description: Some("The schema of the referenced object".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "referenced_name".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The name of the referenced object; the table name for columns".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "referenced_column".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The column name, when the referenced object is a column".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "referenced_identity".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The qualified name of the referenced object, unique among objects of its type".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "dependency_type".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The kind of dependency: normal, auto, internal, partition_primary, partition_secondary, extension or auto_extension".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "depth".into(),
+                models::ObjectField {
+                    description: Some(
+                        "How many dependencies away the referencing object is from the referenced object; 1 for direct dependents".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let view_column_usage_type = models::ObjectType {
+        description: Some("A source column used by a view column".into()),
+        fields: HashMap::from_iter([
+            (
+                "view_schema".into(),
+                models::ObjectField {
+                    description: Some("The schema of the view".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "view_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the view or materialized view".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "view_column_name".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The view column the source column is passed through to; null when the source column is only used in expressions, joins or filters".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "is_materialized".into(),
+                models::ObjectField {
+                    description: Some("Whether the view is a materialized view".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("The schema of the source table or view".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the source table or view".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "column_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the source column".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let database_type = models::ObjectType {
+        description: Some("A database on the Postgres server".into()),
+        fields: HashMap::from_iter([
+            (
+                "database_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the database".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "owner".into(),
+                models::ObjectField {
+                    description: Some("The role that owns the database".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "encoding".into(),
+                models::ObjectField {
+                    description: Some("The character encoding of the database".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "collation".into(),
+                models::ObjectField {
+                    description: Some("The LC_COLLATE of the database".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "ctype".into(),
+                models::ObjectField {
+                    description: Some("The LC_CTYPE of the database".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "size_bytes".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The size of the database on disk in bytes; null when the role cannot connect to it".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "tablespace".into(),
+                models::ObjectField {
+                    description: Some("The default tablespace of the database".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "is_template".into(),
+                models::ObjectField {
+                    description: Some("Whether the database is a template".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "allow_connections".into(),
+                models::ObjectField {
+                    description: Some("Whether connections to the database are allowed".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "connection_limit".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The maximum number of concurrent connections; -1 for no limit".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+            (
+                "is_current".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether this is the database the connector is connected to".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "comment".into(),
+                models::ObjectField {
+                    description: Some("The comment on the database".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let server_info_type = models::ObjectType {
+        description: Some("The Postgres server".into()),
+        fields: HashMap::from_iter([
+            (
+                "server_version".into(),
+                models::ObjectField {
+                    description: Some("The server version, like 15.3".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "server_version_num".into(),
+                models::ObjectField {
+                    description: Some("The server version as a number, like 150003".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named { name: "Int".into() },
+                },
+            ),
+            (
+                "version".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The full version string, including the platform and compiler".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "current_database".into(),
+                models::ObjectField {
+                    description: Some("The database the connector is connected to".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "started_at".into(),
+                models::ObjectField {
+                    description: Some("When the server was started".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Timestamp".into(),
+                    },
+                },
+            ),
+            (
+                "config_loaded_at".into(),
+                models::ObjectField {
+                    description: Some("When the server configuration was last loaded".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Timestamp".into(),
+                    },
+                },
+            ),
+            (
+                "is_in_recovery".into(),
+                models::ObjectField {
+                    description: Some("Whether the server is a standby in recovery".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let setting_type = models::ObjectType {
+        description: Some("A Postgres server setting".into()),
+        fields: HashMap::from_iter([
+            (
+                "name".into(),
+                models::ObjectField {
+                    description: Some("The name of the setting".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "setting".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The current value of the setting, in the unit of the setting".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "unit".into(),
+                models::ObjectField {
+                    description: Some("The unit of the setting, like kB or ms".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "category".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The category of the setting, like Resource Usage / Memory".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "description".into(),
+                models::ObjectField {
+                    description: Some("A short description of the setting".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "context".into(),
+                models::ObjectField {
+                    description: Some(
+                        "When the setting can be changed, like postmaster, sighup or user".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "value_type".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The type of the setting: bool, enum, integer, real or string".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "source".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Where the current value comes from, like default, configuration file or client".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "default_value".into(),
+                models::ObjectField {
+                    description: Some("The built-in default value of the setting".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "reset_value".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The value the setting would be reset to in the current session".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "differs_from_default".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the setting was changed from its built-in default".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "pending_restart".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the setting was changed in the configuration file but needs a restart to apply".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let session_type = models::ObjectType {
+        description: Some("A Postgres server session".into()),
+        fields: HashMap::from_iter([
+            (
+                "pid".into(),
+                models::ObjectField {
+                    description: Some("The process ID of the session's backend".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+            (
+                "database".into(),
+                models::ObjectField {
+                    description: Some("The database the session is connected to".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "username".into(),
+                models::ObjectField {
+                    description: Some("The role the session is logged in as".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "application_name".into(),
+                models::ObjectField {
+                    description: Some("The application name the client set".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "client_address".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The IP address of the client; null for Unix socket and internal connections".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "backend_type".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The type of the backend, like client backend or autovacuum worker".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "state".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The state of the session, like active, idle or idle in transaction".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "wait_event_type".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The type of event the session is waiting on, like Lock or IO".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "wait_event".into(),
+                models::ObjectField {
+                    description: Some("The name of the event the session is waiting on".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "backend_start".into(),
+                models::ObjectField {
+                    description: Some("When the backend was started".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Timestamp".into(),
+                    },
+                },
+            ),
+            (
+                "transaction_start".into(),
+                models::ObjectField {
+                    description: Some("When the current transaction was started".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "query_start".into(),
+                models::ObjectField {
+                    description: Some("When the current or last query was started".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "state_change".into(),
+                models::ObjectField {
+                    description: Some("When the state of the session last changed".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "transaction_duration_seconds".into(),
+                models::ObjectField {
+                    description: Some(
+                        "How long the current transaction has been running, in seconds".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Float".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "query_duration_seconds".into(),
+                models::ObjectField {
+                    description: Some(
+                        "How long the current query has been running, in seconds; null when no query is running".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Float".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "query".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The text of the current or last query; literals are replaced with ? when the connector redacts query texts".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "blocked_by".into(),
+                models::ObjectField {
+                    description: Some("The pids of the sessions blocking this one".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Array {
+                        element_type: Box::new(models::Type::Named {
+                            name: "Int".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let lock_type = models::ObjectType {
+        description: Some("A lock held or awaited by a Postgres session".into()),
+        fields: HashMap::from_iter([
+            (
+                "pid".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The process ID of the session holding or awaiting the lock; null for prepared transactions".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Int".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "lock_type".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The type of the locked object, like relation, tuple or transactionid".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "mode".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The lock mode, like AccessShareLock or RowExclusiveLock".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "granted".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the lock is held; false when the session is waiting for it".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "fastpath".into(),
+                models::ObjectField {
+                    description: Some("Whether the lock was taken through the fast path".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "database".into(),
+                models::ObjectField {
+                    description: Some("The database of the locked object".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The schema of the locked relation, for relations of the current database".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The name of the locked relation, for relations of the current database".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "page".into(),
+                models::ObjectField {
+                    description: Some("The locked page within the relation".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Int".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "tuple".into(),
+                models::ObjectField {
+                    description: Some("The locked tuple within the page".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Int".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "virtual_transaction_id".into(),
+                models::ObjectField {
+                    description: Some("The locked virtual transaction ID".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "transaction_id".into(),
+                models::ObjectField {
+                    description: Some("The locked transaction ID".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "holder_virtual_transaction_id".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The virtual transaction ID of the transaction holding or awaiting the lock".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let blocking_chain_type = models::ObjectType {
+        description: Some("A session blocked by another session".into()),
+        fields: HashMap::from_iter([
+            (
+                "blocked_pid".into(),
+                models::ObjectField {
+                    description: Some("The pid of the waiting session".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+            (
+                "blocking_pid".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The pid of a session the waiting session is blocked by, directly or through other sessions".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+            (
+                "depth".into(),
+                models::ObjectField {
+                    description: Some(
+                        "1 when the blocking session blocks the waiting session directly, 2 when it blocks a session that blocks it, and so on".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+            (
+                "chain".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The pids from the waiting session to the blocking session".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Array {
+                        element_type: Box::new(models::Type::Named {
+                            name: "Int".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "is_root_blocker".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the blocking session is not waiting on any other session itself".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "blocked_username".into(),
+                models::ObjectField {
+                    description: Some("The role of the waiting session".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "blocked_wait_event_type".into(),
+                models::ObjectField {
+                    description: Some("The type of event the waiting session is waiting on".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "blocked_duration_seconds".into(),
+                models::ObjectField {
+                    description: Some(
+                        "How long the query of the waiting session has been running, in seconds".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Float".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "blocked_query".into(),
+                models::ObjectField {
+                    description: Some("The query of the waiting session".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "blocking_username".into(),
+                models::ObjectField {
+                    description: Some("The role of the blocking session".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "blocking_state".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The state of the blocking session, like active or idle in transaction".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "blocking_transaction_duration_seconds".into(),
+                models::ObjectField {
+                    description: Some(
+                        "How long the transaction of the blocking session has been running, in seconds".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Float".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "blocking_query".into(),
+                models::ObjectField {
+                    description: Some("The current or last query of the blocking session".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let publication_type = models::ObjectType {
+        description: Some("A logical replication publication".into()),
+        fields: HashMap::from_iter([
+            (
+                "publication_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the publication".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "owner".into(),
+                models::ObjectField {
+                    description: Some("The role that owns the publication".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "all_tables".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the publication includes every table of the database, including future ones".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "publishes_insert".into(),
+                models::ObjectField {
+                    description: Some("Whether INSERTs are replicated".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "publishes_update".into(),
+                models::ObjectField {
+                    description: Some("Whether UPDATEs are replicated".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "publishes_delete".into(),
+                models::ObjectField {
+                    description: Some("Whether DELETEs are replicated".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "publishes_truncate".into(),
+                models::ObjectField {
+                    description: Some("Whether TRUNCATEs are replicated".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "via_partition_root".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether changes to partitions are published as changes to their partitioned table".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "table_count".into(),
+                models::ObjectField {
+                    description: Some("The number of tables the publication includes".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let publication_table_type = models::ObjectType {
+        description: Some("A table included in a publication".into()),
+        fields: HashMap::from_iter([
+            (
+                "publication_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the publication".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("The schema of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let subscription_type = models::ObjectType {
+        description: Some("A logical replication subscription".into()),
+        fields: HashMap::from_iter([
+            (
+                "subscription_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the subscription".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "database".into(),
+                models::ObjectField {
+                    description: Some("The database the subscription replicates into".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "owner".into(),
+                models::ObjectField {
+                    description: Some("The role that owns the subscription".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "enabled".into(),
+                models::ObjectField {
+                    description: Some("Whether the subscription is enabled".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "slot_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the replication slot on the publisher".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "publications".into(),
+                models::ObjectField {
+                    description: Some("The publications the subscription subscribes to".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Array {
+                        element_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "worker_pid".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The pid of the apply worker; null when it is not running".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named { name: "Int".into() }),
+                    },
+                },
+            ),
+            (
+                "received_lsn".into(),
+                models::ObjectField {
+                    description: Some("The last WAL location received from the publisher".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "latest_end_lsn".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The last WAL location reported back to the publisher".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "last_message_received_at".into(),
+                models::ObjectField {
+                    description: Some(
+                        "When the last message was received from the publisher".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "latest_end_at".into(),
+                models::ObjectField {
+                    description: Some(
+                        "When the last WAL location was reported back to the publisher".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let replication_slot_type = models::ObjectType {
+        description: Some("A replication slot".into()),
+        fields: HashMap::from_iter([
+            (
+                "slot_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the slot".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "slot_type".into(),
+                models::ObjectField {
+                    description: Some("physical or logical".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "plugin".into(),
+                models::ObjectField {
+                    description: Some("The output plugin of a logical slot".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "database".into(),
+                models::ObjectField {
+                    description: Some("The database of a logical slot".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "temporary".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the slot is dropped at the end of the session".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "active".into(),
+                models::ObjectField {
+                    description: Some("Whether a consumer is connected to the slot".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "active_pid".into(),
+                models::ObjectField {
+                    description: Some("The pid of the session using the slot".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Int".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "restart_lsn".into(),
+                models::ObjectField {
+                    description: Some("The oldest WAL location the slot still needs".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "confirmed_flush_lsn".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The WAL location up to which the consumer of a logical slot has confirmed receiving data".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "wal_status".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the WAL the slot needs is still available: reserved, extended, unreserved or lost".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "retained_bytes".into(),
+                models::ObjectField {
+                    description: Some(
+                        "How many bytes of WAL the slot keeps from being removed".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "lag_bytes".into(),
+                models::ObjectField {
+                    description: Some(
+                        "How many bytes of WAL the consumer of a logical slot is behind".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let replication_status_type = models::ObjectType {
+        description: Some("A WAL sender streaming to a standby or subscriber".into()),
+        fields: HashMap::from_iter([
+            (
+                "pid".into(),
+                models::ObjectField {
+                    description: Some("The pid of the WAL sender".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Int".into(),
+                    },
+                },
+            ),
+            (
+                "username".into(),
+                models::ObjectField {
+                    description: Some("The role the receiver connected as".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "application_name".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The application name of the receiver, usually the standby or subscription name".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "client_address".into(),
+                models::ObjectField {
+                    description: Some("The IP address of the receiver".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "backend_start".into(),
+                models::ObjectField {
+                    description: Some("When the receiver connected".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Timestamp".into(),
+                    },
+                },
+            ),
+            (
+                "state".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The state of the WAL sender, like catchup or streaming".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "sync_state".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The synchronous state of the standby: async, potential, sync or quorum".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "sent_lsn".into(),
+                models::ObjectField {
+                    description: Some("The last WAL location sent".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "write_lsn".into(),
+                models::ObjectField {
+                    description: Some("The last WAL location written by the receiver".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "flush_lsn".into(),
+                models::ObjectField {
+                    description: Some("The last WAL location flushed by the receiver".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "replay_lsn".into(),
+                models::ObjectField {
+                    description: Some("The last WAL location replayed by the receiver".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "replay_lag_bytes".into(),
+                models::ObjectField {
+                    description: Some(
+                        "How many bytes of WAL the receiver has yet to replay".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "write_lag_seconds".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The time it took to write recent WAL on the receiver, in seconds".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Float".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "flush_lag_seconds".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The time it took to flush recent WAL on the receiver, in seconds".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Float".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "replay_lag_seconds".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The time it took to replay recent WAL on the receiver, in seconds".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Float".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "last_reply_at".into(),
+                models::ObjectField {
+                    description: Some("When the last reply was received from the receiver".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "Timestamp".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let index_health_type = models::ObjectType {
+        description: Some("A finding about an index, or a foreign key without one".into()),
+        fields: HashMap::from_iter([
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("The schema of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "index_name".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The name of the index; null for unindexed_foreign_key findings".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "constraint_name".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The name of the foreign key of an unindexed_foreign_key finding".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "finding".into(),
+                models::ObjectField {
+                    description: Some(
+                        "invalid_index, duplicate_index, overlapping_index, unused_index or unindexed_foreign_key".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "severity".into(),
+                models::ObjectField {
+                    description: Some("high, medium or low".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "details".into(),
+                models::ObjectField {
+                    description: Some("A description of the finding".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "related_index_name".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The index that makes this one redundant, for duplicate_index and overlapping_index findings".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "index_size".into(),
+                models::ObjectField {
+                    description: Some("The size of the index in bytes".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "idx_scan".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The number of scans of the index since the statistics were last reset".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "idx_tup_read".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The number of index entries returned by scans of the index".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "BigInt".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "suggested_sql".into(),
+                models::ObjectField {
+                    description: Some(
+                        "A DROP INDEX or CREATE INDEX statement that addresses the finding".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let lint_finding_type = models::ObjectType {
+        description: Some("A finding of a schema lint rule".into()),
+        fields: HashMap::from_iter([
+            (
+                "rule_id".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The id of the lint rule, like table_without_primary_key".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "severity".into(),
+                models::ObjectField {
+                    description: Some("error, warning or info".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "object_type".into(),
+                models::ObjectField {
+                    description: Some("table, column or foreign_key".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("The schema of the table the object belongs to".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the table the object belongs to".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "object_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the table, column or foreign key".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "message".into(),
+                models::ObjectField {
+                    description: Some("A description of the finding".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let ddl_type = models::ObjectType {
+        description: Some("The DDL of a table, view, materialized view or foreign table".into()),
+        fields: HashMap::from_iter([
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("The schema of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "ddl".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The CREATE statement of the table, followed by its CREATE INDEX, COMMENT ON, GRANT and CREATE TRIGGER statements".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+
+    let index_type = models::ObjectType {
+        description: Some("An index of a table or materialized view".into()),
+        fields: HashMap::from_iter([
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("The schema of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "index_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the index".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "access_method".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The access method of the index, like btree, hash, gin or gist".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "is_primary".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the index backs the primary key of the table".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "is_unique".into(),
+                models::ObjectField {
+                    description: Some("Whether the index is unique".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "is_valid".into(),
+                models::ObjectField {
+                    description: Some(
+                        "Whether the index is valid; an index that failed to build concurrently is not".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "Boolean".into(),
+                    },
+                },
+            ),
+            (
+                "constraint_name".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The primary key, unique or exclusion constraint the index backs, if any".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "key_columns".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The columns or expressions of the index key, in order".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Array {
+                        element_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "predicate".into(),
+                models::ObjectField {
+                    description: Some("The predicate of a partial index".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "definition".into(),
+                models::ObjectField {
+                    description: Some("The CREATE INDEX statement of the index".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "comment".into(),
+                models::ObjectField {
+                    description: Some("The comment on the index".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+    // ANCHOR_END: schema_object_type_author
+    // ANCHOR: schema_object_types
+    let mut object_types: HashMap<String, models::ObjectType> = HashMap::from_iter([
+        ("table".into(), table_type),
+        ("column".into(), column_type),
+        ("foreign_key".into(), foreign_key_type),
+        ("table_stat".into(), table_stats_type),
+        ("column_stat".into(), column_stats_type),
+        ("sequence".into(), sequence_type),
+        ("extension".into(), extension_type),
+        ("foreign_server".into(), foreign_server_type),
+        ("user_mapping".into(), user_mapping_type),
+        ("foreign_table".into(), foreign_table_type),
+        ("role".into(), role_type),
+        ("table_privilege".into(), table_privilege_type),
+        ("policy".into(), policy_type),
+        ("table_inheritance".into(), table_inheritance_type),
+        ("dependency".into(), dependency_type),
+        ("view_column_usage".into(), view_column_usage_type),
+        ("database".into(), database_type),
+        ("server_info".into(), server_info_type),
+        ("setting".into(), setting_type),
+        ("session".into(), session_type),
+        ("lock".into(), lock_type),
+        ("blocking_chain".into(), blocking_chain_type),
+        ("publication".into(), publication_type),
+        ("publication_table".into(), publication_table_type),
+        ("subscription".into(), subscription_type),
+        ("replication_slot".into(), replication_slot_type),
+        ("replication_status".into(), replication_status_type),
+        ("index_health".into(), index_health_type),
+        ("lint_finding".into(), lint_finding_type),
+        ("ddl".into(), ddl_type),
+        ("index".into(), index_type),
+    ]);
+
+    // every row can be tagged with the database it was read from, see the all_databases argument
+    for object_type in object_types.values_mut() {
+        object_type
+            .fields
+            .entry("database_name".into())
+            .or_insert(models::ObjectField {
+                description: Some("The name of the database the row was read from".into()),
+                arguments: HashMap::new(),
+                r#type: models::Type::Named {
+                    name: "String".into(),
+                },
+            });
+    }
+
+    // the result types of the commands, which are not read from a database
+    let schema_difference_type = models::ObjectType {
+        description: Some("A difference between the schemas of two databases".into()),
+        fields: HashMap::from_iter([
+            (
+                "change".into(),
+                models::ObjectField {
+                    description: Some("added, removed or changed".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "object_type".into(),
+                models::ObjectField {
+                    description: Some("table, column, constraint or index".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_schema".into(),
+                models::ObjectField {
+                    description: Some("The schema of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "table_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the table".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "object_name".into(),
+                models::ObjectField {
+                    description: Some("The name of the table, column, constraint or index".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "property".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The property that changed, like data_type; null for added and removed objects".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "before".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The value of the property, or the definition of a removed object, in the from database".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "after".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The value of the property, or the definition of an added object, in the to database".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+    let schema_diff_type = models::ObjectType {
+        description: Some("The differences between the schemas of two databases".into()),
+        fields: HashMap::from_iter([
+            (
+                "differences".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The added, removed and changed tables, columns, constraints and indexes".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Array {
+                        element_type: Box::new(models::Type::Named {
+                            name: "schema_difference".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "migration_script".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The SQL that migrates the from database to the to database, if include_migration_script is set".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                },
+            ),
+        ]),
+    };
+    let snapshot_type = models::ObjectType {
+        description: Some("The catalog of a database, as served by this connector".into()),
+        fields: HashMap::from_iter([
+            (
+                "version".into(),
+                models::ObjectField {
+                    description: Some("The version of the snapshot document".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named { name: "Int".into() },
+                },
+            ),
+            (
+                "created_at".into(),
+                models::ObjectField {
+                    description: Some("When the snapshot was taken".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "database".into(),
+                models::ObjectField {
+                    description: Some("The name of the database the snapshot was taken of".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "server_version".into(),
+                models::ObjectField {
+                    description: Some(
+                        "The version of the Postgres server the snapshot was taken of".into(),
+                    ),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "String".into(),
+                    },
+                },
+            ),
+            (
+                "collections".into(),
+                models::ObjectField {
+                    description: Some("The rows of every table, by table name".into()),
+                    arguments: HashMap::new(),
+                    r#type: models::Type::Named {
+                        name: "JSON".into(),
+                    },
+                },
+            ),
+        ]),
+    };
+    object_types.insert("schema_difference".into(), schema_difference_type);
+    object_types.insert("schema_diff".into(), schema_diff_type);
+    object_types.insert("snapshot".into(), snapshot_type);
+
+    // the arguments of the tables that are shared by every database on the server, which cannot
+    // fan out to all of them
+    let server_arguments: HashMap<String, models::ArgumentInfo> = HashMap::from_iter([(
+        "database_url".into(),
+        models::ArgumentInfo {
+            description: Some(
+                "The PG connection URI of the Postgres database that you wish to get entities from"
+                    .into(),
+            ),
+            argument_type: models::Type::Named {
+                name: "database_url".into(),
+            },
+        },
+    )]);
+
+    let mut database_arguments = server_arguments.clone();
+    database_arguments.insert(
+        "all_databases".into(),
+        models::ArgumentInfo {
+            description: Some(
+                "Get the entities of every database on the server the database_url points to; defaults to false"
+                    .into(),
+            ),
+            argument_type: models::Type::Nullable {
+                underlying_type: Box::new(models::Type::Named {
+                    name: "Boolean".into(),
+                }),
+            },
+        },
+    );
+
+    let mut tables_arguments = database_arguments.clone();
+    tables_arguments.insert(
+        "exclude_partitions".into(),
+        models::ArgumentInfo {
+            description: Some(
+                "Leave out the leaf partitions of partitioned tables; defaults to false".into(),
+            ),
+            argument_type: models::Type::Nullable {
+                underlying_type: Box::new(models::Type::Named {
+                    name: "Boolean".into(),
+                }),
+            },
+        },
+    );
+
+    let tables_table = models::TableInfo {
+        name: "tables".into(),
+        description: Some("A collection of Postgres tables".into()),
+        table_type: "table".into(),
+        arguments: tables_arguments,
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "TableSchemaName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["table_schema".into(), "table_name".into()],
+            },
+        )]),
+    };
+
+    let columns_table = models::TableInfo {
+        name: "columns".into(),
+        description: Some("A collection of Postgres columns".into()),
+        table_type: "column".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "ColumnName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec![
+                    "table_schema".into(),
+                    "table_name".into(),
+                    "column_name".into(),
+                ],
+            },
+        )]),
+    };
+
+    let foreign_keys_table = models::TableInfo {
+        name: "foreign_keys".into(),
+        description: Some("A collection of Postgres foreign keys".into()),
+        table_type: "foreign_key".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "ForeignKeyName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["fkey_name".into()],
+            },
+        )]),
+    };
+
+    let table_stats_table = models::TableInfo {
+        name: "table_stats".into(),
+        description: Some("A collection of Postgres table statistics".into()),
+        table_type: "table_stat".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "TableStatsName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["table_schema".into(), "table_name".into()],
+            },
+        )]),
+    };
+
+    let column_stats_table = models::TableInfo {
+        name: "column_stats".into(),
+        description: Some("A collection of Postgres column statistics from pg_stats. Statistics are only available after the table was analyzed, and only for tables that the connecting role can SELECT from; other tables are left out of the result rather than reported as an error.".into()),
+        table_type: "column_stat".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([
+            (
+                "ColumnStatsName".into(),
+                models::UniquenessConstraint {
+                    unique_columns: vec!["table_schema".into(), "table_name".into(), "column_name".into(), "inherited".into()],
+                },
+            ),
+        ]),
+    };
+
+    let sequences_table = models::TableInfo {
+        name: "sequences".into(),
+        description: Some("A collection of Postgres sequences".into()),
+        table_type: "sequence".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "SequenceName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["sequence_schema".into(), "sequence_name".into()],
+            },
+        )]),
+    };
+
+    let extensions_table = models::TableInfo {
+        name: "extensions".into(),
+        description: Some("A collection of the Postgres extensions that are available on the server, installed or not".into()),
+        table_type: "extension".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([
+            (
+                "ExtensionName".into(),
+                models::UniquenessConstraint {
+                    unique_columns: vec!["extension_name".into()],
+                },
+            ),
         ]),
     };
-    // ANCHOR_END: schema_object_type_author
-    // ANCHOR: schema_object_types
-    let object_types = HashMap::from_iter([
-        ("tables".into(), table_type),
-        ("columns".into(), column_type),
-        ("foreign_keys".into(), foreign_key_type),
-    ]);
 
-    let database_url_argument: HashMap<String, models::ArgumentInfo> = HashMap::from_iter([(
-        "database_url".into(),
+    let foreign_servers_table = models::TableInfo {
+        name: "foreign_servers".into(),
+        description: Some("A collection of Postgres foreign servers".into()),
+        table_type: "foreign_server".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "ForeignServerName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["server_name".into()],
+            },
+        )]),
+    };
+
+    let user_mappings_table = models::TableInfo {
+        name: "user_mappings".into(),
+        description: Some(
+            "A collection of Postgres user mappings, without their option values".into(),
+        ),
+        table_type: "user_mapping".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "UserMappingName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["server_name".into(), "user_name".into()],
+            },
+        )]),
+    };
+
+    let foreign_tables_table = models::TableInfo {
+        name: "foreign_tables".into(),
+        description: Some("A collection of Postgres foreign tables".into()),
+        table_type: "foreign_table".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "ForeignTableName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["table_schema".into(), "table_name".into()],
+            },
+        )]),
+    };
+
+    let roles_table = models::TableInfo {
+        name: "roles".into(),
+        description: Some("A collection of Postgres roles. Password data is not exposed.".into()),
+        table_type: "role".into(),
+        arguments: server_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "RoleName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["role_name".into()],
+            },
+        )]),
+    };
+
+    let table_privileges_table = models::TableInfo {
+        name: "table_privileges".into(),
+        description: Some("A collection of the privileges granted on Postgres tables and columns, expanded from their ACLs".into()),
+        table_type: "table_privilege".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::new(),
+    };
+
+    let policies_table = models::TableInfo {
+        name: "policies".into(),
+        description: Some("A collection of Postgres row-level security policies".into()),
+        table_type: "policy".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "PolicyName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec![
+                    "table_schema".into(),
+                    "table_name".into(),
+                    "policy_name".into(),
+                ],
+            },
+        )]),
+    };
+
+    let table_inheritance_table = models::TableInfo {
+        name: "table_inheritance".into(),
+        description: Some("A collection of the inheritance and partitioning parents of Postgres tables, from pg_inherits".into()),
+        table_type: "table_inheritance".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([
+            (
+                "TableInheritanceName".into(),
+                models::UniquenessConstraint {
+                    unique_columns: vec!["table_schema".into(), "table_name".into(), "parent_schema".into(), "parent_name".into()],
+                },
+            ),
+        ]),
+    };
+
+    let mut dependencies_arguments = database_arguments.clone();
+    dependencies_arguments.insert(
+        "max_depth".into(),
         models::ArgumentInfo {
             description: Some(
-                "The PG connection URI of the Postgres database that you wish to get entities from"
+                "How many levels of transitive dependents to walk, between 1 and 10; defaults to 1"
                     .into(),
             ),
-            argument_type: models::Type::Named {
-                name: "database_url".into(),
+            argument_type: models::Type::Nullable {
+                underlying_type: Box::new(models::Type::Named { name: "Int".into() }),
             },
         },
-    )]);
+    );
 
-    let tables_table = models::TableInfo {
-        name: "tables".into(),
-        description: Some("A collection of Postgres tables".into()),
-        table_type: "table".into(),
-        arguments: database_url_argument.clone(),
+    let dependencies_table = models::TableInfo {
+        name: "dependencies".into(),
+        description: Some("A collection of Postgres object dependencies".into()),
+        table_type: "dependency".into(),
+        arguments: dependencies_arguments,
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::new(),
+    };
+
+    let view_column_usage_table = models::TableInfo {
+        name: "view_column_usage".into(),
+        description: Some("A collection of Postgres view column lineage".into()),
+        table_type: "view_column_usage".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::new(),
+    };
+
+    let databases_table = models::TableInfo {
+        name: "databases".into(),
+        description: Some("A collection of the databases on the Postgres server".into()),
+        table_type: "database".into(),
+        arguments: server_arguments.clone(),
         deletable: false,
         insertable_columns: None,
         updatable_columns: None,
         foreign_keys: HashMap::new(),
         uniqueness_constraints: HashMap::from_iter([(
-            "TableSchemaName".into(),
+            "DatabaseName".into(),
             models::UniquenessConstraint {
-                unique_columns: vec!["table_schema".into(), "table_name".into()],
+                unique_columns: vec!["database_name".into()],
             },
         )]),
     };
 
-    let columns_table = models::TableInfo {
-        name: "columns".into(),
-        description: Some("A collection of Postgres columns".into()),
-        table_type: "column".into(),
-        arguments: database_url_argument.clone(),
+    let server_info_table = models::TableInfo {
+        name: "server_info".into(),
+        description: Some(
+            "The Postgres server the connector is connected to, as a single row".into(),
+        ),
+        table_type: "server_info".into(),
+        arguments: server_arguments.clone(),
         deletable: false,
         insertable_columns: None,
         updatable_columns: None,
-        foreign_keys: HashMap::from_iter([(
-            "ColumnToTable".into(),
-            models::ForeignKeyConstraint {
-                column_mapping: HashMap::from_iter([
-                    ("table_schema".into(), "table_schema".into()),
-                    ("table_name".into(), "table_name".into()),
-                ]),
-                foreign_table: "table".into(),
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::new(),
+    };
+
+    let settings_table = models::TableInfo {
+        name: "settings".into(),
+        description: Some("A collection of Postgres server settings".into()),
+        table_type: "setting".into(),
+        arguments: server_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "SettingName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["name".into()],
             },
         )]),
+    };
+
+    let sessions_table = models::TableInfo {
+        name: "sessions".into(),
+        description: Some(
+            "A collection of the sessions on the Postgres server, from pg_stat_activity".into(),
+        ),
+        table_type: "session".into(),
+        arguments: server_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
         uniqueness_constraints: HashMap::from_iter([(
-            "ColumnName".into(),
+            "SessionPid".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["pid".into()],
+            },
+        )]),
+    };
+
+    let locks_table = models::TableInfo {
+        name: "locks".into(),
+        description: Some("A collection of the locks on the Postgres server, from pg_locks".into()),
+        table_type: "lock".into(),
+        arguments: server_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::new(),
+    };
+
+    let blocking_chains_table = models::TableInfo {
+        name: "blocking_chains".into(),
+        description: Some("A collection of the sessions waiting on other sessions, with the whole chain of sessions blocking each one".into()),
+        table_type: "blocking_chain".into(),
+        arguments: server_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::new(),
+    };
+
+    let publications_table = models::TableInfo {
+        name: "publications".into(),
+        description: Some("A collection of Postgres publications".into()),
+        table_type: "publication".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "PublicationName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["publication_name".into()],
+            },
+        )]),
+    };
+
+    let publication_tables_table = models::TableInfo {
+        name: "publication_tables".into(),
+        description: Some("A collection of the tables included in Postgres publications".into()),
+        table_type: "publication_table".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "PublicationTableName".into(),
             models::UniquenessConstraint {
                 unique_columns: vec![
+                    "publication_name".into(),
                     "table_schema".into(),
                     "table_name".into(),
-                    "column_name".into(),
                 ],
             },
         )]),
     };
 
-    let foreign_keys_table = models::TableInfo {
-        name: "foreign_keys".into(),
-        description: Some("A collection of Postgres foreign keys".into()),
-        table_type: "foreign_key".into(),
-        arguments: database_url_argument.clone(),
+    let subscriptions_table = models::TableInfo {
+        name: "subscriptions".into(),
+        description: Some("A collection of Postgres subscriptions".into()),
+        table_type: "subscription".into(),
+        arguments: server_arguments.clone(),
         deletable: false,
         insertable_columns: None,
         updatable_columns: None,
         foreign_keys: HashMap::new(),
         uniqueness_constraints: HashMap::from_iter([(
-            "ForeignKeyName".into(),
+            "SubscriptionName".into(),
             models::UniquenessConstraint {
-                unique_columns: vec!["fkey_name".into()],
+                unique_columns: vec!["subscription_name".into()],
+            },
+        )]),
+    };
+
+    let replication_slots_table = models::TableInfo {
+        name: "replication_slots".into(),
+        description: Some("A collection of Postgres replication slots".into()),
+        table_type: "replication_slot".into(),
+        arguments: server_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "ReplicationSlotName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["slot_name".into()],
+            },
+        )]),
+    };
+
+    let replication_status_table = models::TableInfo {
+        name: "replication_status".into(),
+        description: Some(
+            "A collection of the WAL senders of the server, from pg_stat_replication".into(),
+        ),
+        table_type: "replication_status".into(),
+        arguments: server_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::new(),
+    };
+
+    let index_health_table = models::TableInfo {
+        name: "index_health".into(),
+        description: Some("A collection of index health findings".into()),
+        table_type: "index_health".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::new(),
+    };
+
+    let lint_findings_table = models::TableInfo {
+        name: "lint_findings".into(),
+        description: Some(
+            "A collection of the findings of the schema lint rules that are enabled".into(),
+        ),
+        table_type: "lint_finding".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::new(),
+    };
+
+    let ddl_table = models::TableInfo {
+        name: "ddl".into(),
+        description: Some("A collection of the DDL of Postgres tables, views, materialized views and foreign tables".into()),
+        table_type: "ddl".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([
+            (
+                "DdlTableName".into(),
+                models::UniquenessConstraint {
+                    unique_columns: vec!["table_schema".into(), "table_name".into()],
+                },
+            ),
+        ]),
+    };
+
+    let indexes_table = models::TableInfo {
+        name: "indexes".into(),
+        description: Some("The indexes of the tables and materialized views".into()),
+        table_type: "index".into(),
+        arguments: database_arguments.clone(),
+        deletable: false,
+        insertable_columns: None,
+        updatable_columns: None,
+        foreign_keys: HashMap::new(),
+        uniqueness_constraints: HashMap::from_iter([(
+            "IndexSchemaName".into(),
+            models::UniquenessConstraint {
+                unique_columns: vec!["table_schema".into(), "index_name".into()],
             },
         )]),
     };
 
-    let tables = vec![tables_table, columns_table, foreign_keys_table];
+    let tables = vec![
+        tables_table,
+        columns_table,
+        foreign_keys_table,
+        table_stats_table,
+        column_stats_table,
+        sequences_table,
+        extensions_table,
+        foreign_servers_table,
+        user_mappings_table,
+        foreign_tables_table,
+        roles_table,
+        table_privileges_table,
+        policies_table,
+        table_inheritance_table,
+        dependencies_table,
+        view_column_usage_table,
+        databases_table,
+        server_info_table,
+        settings_table,
+        sessions_table,
+        locks_table,
+        blocking_chains_table,
+        publications_table,
+        publication_tables_table,
+        subscriptions_table,
+        replication_slots_table,
+        replication_status_table,
+        index_health_table,
+        lint_findings_table,
+        ddl_table,
+        indexes_table,
+    ];
 
     // ANCHOR: schema_commands
-    let commands = vec![];
+    let commands = vec![
+        models::CommandInfo {
+            name: "schema_diff".into(),
+            description: Some(
+                "Compare the tables, columns, constraints and indexes of two databases".into(),
+            ),
+            arguments: HashMap::from_iter([
+                (
+                    "from".into(),
+                    models::ArgumentInfo {
+                        description: Some(
+                            "The database to migrate from: a source name from SOURCES, or a PG connection URI with ALLOW_SOURCE_URLS"
+                                .into(),
+                        ),
+                        argument_type: models::Type::Named {
+                            name: "String".into(),
+                        },
+                    },
+                ),
+                (
+                    "to".into(),
+                    models::ArgumentInfo {
+                        description: Some(
+                            "The database to migrate to: a source name from SOURCES, or a PG connection URI with ALLOW_SOURCE_URLS"
+                                .into(),
+                        ),
+                        argument_type: models::Type::Named {
+                            name: "String".into(),
+                        },
+                    },
+                ),
+                (
+                    "include_migration_script".into(),
+                    models::ArgumentInfo {
+                        description: Some(
+                            "Also return the SQL that migrates the from database to the to database; defaults to false"
+                                .into(),
+                        ),
+                        argument_type: models::Type::Nullable {
+                            underlying_type: Box::new(models::Type::Named {
+                                name: "Boolean".into(),
+                            }),
+                        },
+                    },
+                ),
+            ]),
+            result_type: models::Type::Named {
+                name: "schema_diff".into(),
+            },
+        },
+        models::CommandInfo {
+            name: "snapshot".into(),
+            description: Some(
+                "Serialize every row of every table of a database into a snapshot, which can be queried with a snapshot: database URL".into(),
+            ),
+            arguments: HashMap::from_iter([(
+                "database_url".into(),
+                models::ArgumentInfo {
+                    description: Some(
+                        "The PG connection URI of the Postgres database to take a snapshot of".into(),
+                    ),
+                    argument_type: models::Type::Nullable {
+                        underlying_type: Box::new(models::Type::Named {
+                            name: "database_url".into(),
+                        }),
+                    },
+                },
+            )]),
+            result_type: models::Type::Named {
+                name: "snapshot".into(),
+            },
+        },
+        models::CommandInfo {
+            name: "er_diagram".into(),
+            description: Some(
+                "Render the tables of a database, their key columns and the foreign keys between them as an ER diagram".into(),
+            ),
+            arguments: HashMap::from_iter([
+                (
+                    "database_url".into(),
+                    models::ArgumentInfo {
+                        description: Some(
+                            "The PG connection URI of the Postgres database to draw the tables of".into(),
+                        ),
+                        argument_type: models::Type::Nullable {
+                            underlying_type: Box::new(models::Type::Named {
+                                name: "database_url".into(),
+                            }),
+                        },
+                    },
+                ),
+                (
+                    "format".into(),
+                    models::ArgumentInfo {
+                        description: Some(
+                            "The notation of the diagram: mermaid, dot or plantuml; defaults to mermaid".into(),
+                        ),
+                        argument_type: models::Type::Nullable {
+                            underlying_type: Box::new(models::Type::Named {
+                                name: "String".into(),
+                            }),
+                        },
+                    },
+                ),
+                (
+                    "schema".into(),
+                    models::ArgumentInfo {
+                        description: Some(
+                            "Only draw the tables of this schema".into(),
+                        ),
+                        argument_type: models::Type::Nullable {
+                            underlying_type: Box::new(models::Type::Named {
+                                name: "String".into(),
+                            }),
+                        },
+                    },
+                ),
+                (
+                    "table_pattern".into(),
+                    models::ArgumentInfo {
+                        description: Some(
+                            "Only draw the tables whose name matches this LIKE pattern".into(),
+                        ),
+                        argument_type: models::Type::Nullable {
+                            underlying_type: Box::new(models::Type::Named {
+                                name: "String".into(),
+                            }),
+                        },
+                    },
+                ),
+                (
+                    "table".into(),
+                    models::ArgumentInfo {
+                        description: Some(
+                            "Only draw this table, as name or schema.name, and the tables around it".into(),
+                        ),
+                        argument_type: models::Type::Nullable {
+                            underlying_type: Box::new(models::Type::Named {
+                                name: "String".into(),
+                            }),
+                        },
+                    },
+                ),
+                (
+                    "depth".into(),
+                    models::ArgumentInfo {
+                        description: Some(
+                            "How many foreign keys away from table the drawn tables can be; defaults to 1".into(),
+                        ),
+                        argument_type: models::Type::Nullable {
+                            underlying_type: Box::new(models::Type::Named {
+                                name: "Int".into(),
+                            }),
+                        },
+                    },
+                ),
+            ]),
+            result_type: models::Type::Named {
+                name: "String".into(),
+            },
+        },
+    ];
     // ANCHOR_END: schema_commands
 
-    Json(models::SchemaResponse {
+    models::SchemaResponse {
         scalar_types,
         object_types,
         tables,
         commands,
-    })
+    }
 }
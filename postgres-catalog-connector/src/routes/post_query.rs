@@ -1,13 +1,16 @@
 use axum::Json;
-use ndc_client::models::{Argument, QueryRequest, QueryResponse};
+use ndc_client::models::{self, QueryRequest, QueryResponse};
 use sqlx::{
-    postgres::{PgPoolOptions, PgRow},
+    postgres::{PgConnectOptions, PgPoolOptions, PgRow},
     PgPool, Row,
 };
 use std::collections::HashMap;
+use std::str::FromStr;
+use tokio::task::JoinSet;
 // use sqlx::{types, Row};
 // use cc_postgres::configuration::{Configuration};
-use cc_postgres::{error::ServerError, sql};
+use cc_postgres::arguments::{get_argument_value, get_bool_argument, get_database_url};
+use cc_postgres::{commands, er_diagram, error::ServerError, fan_out, schema_diff, snapshot, sql};
 use cc_postgres::configuration;
 
 pub const ROUTENAME: &str = "/query";
@@ -20,26 +23,33 @@ pub async fn handler(
     resolve_query_request(&request).await
 }
 
-async fn resolve_query_request(request: &QueryRequest) -> Result<Json<QueryResponse>, ServerError> {
+pub(crate) async fn resolve_query_request(
+    request: &QueryRequest,
+) -> Result<Json<QueryResponse>, ServerError> {
     // unwrap the variables from Option type; default to HashMap
     let vars = request.variables.clone().unwrap_or(vec![HashMap::new()]);
 
-    // get the database_url variable from arguments or variables
-    let database_url = get_argument_value(&request.arguments, &vars, "database_url".into());
+    // commands are queried like tables, and return their result in a single row
+    if request.table == schema_diff::SCHEMA_DIFF {
+        return resolve_schema_diff_request(request, &vars).await;
+    }
+
+    // get the URL to connect to; either from the request or the default one
+    let db_url = get_database_url(&request.arguments, &vars)?;
 
-    // create a postgres connection with DB URL
-    // todo: brute force code - needs improvement
-    let maybe_pool = match database_url {
-        Some(db_url) => match db_url {
-            serde_json::Value::String(val) => get_sql_connection_pool(val).await,
-            _ => return Err(ServerError::BadRequest("invalid db url".into())),
-        },
-        None => match configuration::get_default_db_url() {
-            Some(db_url) => get_sql_connection_pool(&db_url).await,
-            None => return Err(ServerError::BadRequest("no db url provided".into())),
-        } 
+    // serve the query from a snapshot file instead of a database
+    if let Some(name) = snapshot::get_snapshot_name(&db_url) {
+        let snapshot = snapshot::load_snapshot(name)?;
+        if request.table == snapshot::SNAPSHOT {
+            return get_command_response(request, snapshot::SNAPSHOT, &snapshot);
+        }
+        return serde_json::from_value(snapshot::query_snapshot(&snapshot, request)?)
+            .map(Json)
+            .map_err(|err| ServerError::Internal(err.to_string()));
     }
-    .map_err(|err| {
+
+    // create a postgres connection with DB URL
+    let maybe_pool = get_sql_connection_pool(&db_url).await.map_err(|err| {
         ServerError::BadRequest(format!(
             "could not connect to the given database: {}",
             err.to_string()
@@ -51,33 +61,232 @@ async fn resolve_query_request(request: &QueryRequest) -> Result<Json<QueryRespo
         Err(e) => return Err(e),
     };
 
-    // build the SQL statement from request
-    let sql_statement = sql::build_sql_query(request);
-
-    // execute SQL, build response and return
-    match sql_statement {
-        Ok(statement) => {
-            let result: PgRow = sqlx::query(statement.to_string().as_str())
-                .fetch_one(&pool)
-                .await?;
-            let value: Result<sqlx::types::JsonValue, ServerError> = result
-                .try_get(0)
-                .map_err(|err| ServerError::DatabaseError(err.to_string()));
-            // this parsing is technically not necessary, but useful for validation during development, and to ensure correctness.
-            // todo: remove this, and instead send the first column of the first row as response without parsing or allocating additonal memory
-            match value {
-                Ok(v) => {
-                    let response: Result<Json<QueryResponse>, ServerError> =
-                        serde_json::from_value(v)
-                            .map(|r| Json(r))
-                            .map_err(|err| ServerError::Internal(err.to_string()));
-                    response
+    if request.table == snapshot::SNAPSHOT {
+        return resolve_snapshot_request(request, &pool).await;
+    }
+    if request.table == er_diagram::ER_DIAGRAM {
+        return resolve_er_diagram_request(request, &pool).await;
+    }
+
+    // run the query against the database, or against every database on the server
+    let value = if get_bool_argument(&request.arguments, &vars, "all_databases") {
+        resolve_fan_out_query_request(request, &pool, &db_url).await?
+    } else {
+        let statement =
+            sql::build_sql_query(request, configuration::get_catalog_backend())?.to_string();
+        execute_query(&pool, &statement).await?
+    };
+
+    // this parsing is technically not necessary, but useful for validation during development, and to ensure correctness.
+    // todo: remove this, and instead send the first column of the first row as response without parsing or allocating additonal memory
+    serde_json::from_value(value)
+        .map(Json)
+        .map_err(|err| ServerError::Internal(err.to_string()))
+}
+
+// runs the query against every database on the server, FAN_OUT_CONCURRENCY databases at a time,
+// and merges the rows of all of them
+async fn resolve_fan_out_query_request(
+    request: &QueryRequest,
+    pool: &PgPool,
+    db_url: &str,
+) -> Result<serde_json::Value, ServerError> {
+    let fan_out_request = QueryRequest {
+        query: fan_out::get_fan_out_query(&request.query),
+        ..request.clone()
+    };
+    let statement =
+        sql::build_sql_query(&fan_out_request, configuration::get_catalog_backend())?.to_string();
+
+    let databases: Vec<String> = sqlx::query_scalar(fan_out::FAN_OUT_DATABASES_QUERY)
+        .fetch_all(pool)
+        .await?;
+    let connect_options = PgConnectOptions::from_str(db_url)
+        .map_err(|err| ServerError::BadRequest(format!("invalid db url: {}", err)))?;
+    let concurrency = configuration::get_fan_out_concurrency();
+
+    let mut pending = databases.into_iter().enumerate();
+    let mut running = JoinSet::new();
+    let mut responses = vec![];
+    loop {
+        while running.len() < concurrency {
+            match pending.next() {
+                Some((position, database)) => {
+                    let options = connect_options.clone().database(&database);
+                    let statement = statement.clone();
+                    running.spawn(async move {
+                        let response = match PgPoolOptions::new()
+                            .max_connections(1)
+                            .connect_with(options)
+                            .await
+                        {
+                            Ok(pool) => execute_query(&pool, &statement).await,
+                            Err(err) => Err(err.into()),
+                        };
+                        (position, database, response)
+                    });
                 }
-                Err(e) => Err(e),
+                None => break,
             }
         }
-        Err(e) => Err(e),
+        match running.join_next().await {
+            Some(Ok((position, database, response))) => match response {
+                Ok(r) => responses.push((position, r)),
+                Err(ServerError::DatabaseError(err)) => {
+                    return Err(ServerError::DatabaseError(format!("{}: {}", database, err)))
+                }
+                Err(e) => return Err(e),
+            },
+            Some(Err(err)) => return Err(ServerError::Internal(err.to_string())),
+            None => break,
+        }
     }
+
+    // merge in database name order, regardless of which database answered first
+    responses.sort_by_key(|(position, _)| *position);
+    fan_out::merge_fan_out_responses(
+        &request.query,
+        responses.into_iter().map(|(_, r)| r).collect(),
+    )
+}
+
+// compares the schemas of the from and to databases of the request
+async fn resolve_schema_diff_request(
+    request: &QueryRequest,
+    vars: &[HashMap<String, serde_json::Value>],
+) -> Result<Json<QueryResponse>, ServerError> {
+    let from_url = get_source_url(request, vars, "from")?;
+    let to_url = get_source_url(request, vars, "to")?;
+    let include_migration_script =
+        get_bool_argument(&request.arguments, vars, "include_migration_script");
+
+    let (from, to) = tokio::try_join!(
+        get_schema_objects("from", &from_url),
+        get_schema_objects("to", &to_url)
+    )?;
+    let diff = schema_diff::get_schema_diff(from, to, include_migration_script);
+    get_command_response(request, schema_diff::SCHEMA_DIFF, &diff)
+}
+
+// serializes every row of every table of the database into a snapshot
+async fn resolve_snapshot_request(
+    request: &QueryRequest,
+    pool: &PgPool,
+) -> Result<Json<QueryResponse>, ServerError> {
+    let info = execute_query(pool, snapshot::SNAPSHOT_INFO_QUERY).await?;
+    let mut responses = vec![];
+    for snapshot_request in snapshot::get_snapshot_requests() {
+        let statement =
+            sql::build_sql_query(&snapshot_request, configuration::get_catalog_backend())?
+                .to_string();
+        responses.push((
+            snapshot_request.table,
+            execute_query(pool, &statement).await?,
+        ));
+    }
+    let snapshot = snapshot::get_snapshot(info, responses)?;
+    get_command_response(request, snapshot::SNAPSHOT, &snapshot)
+}
+
+// renders the tables of the database and the foreign keys between them as a diagram
+async fn resolve_er_diagram_request(
+    request: &QueryRequest,
+    pool: &PgPool,
+) -> Result<Json<QueryResponse>, ServerError> {
+    let arguments = er_diagram::ErDiagramArguments::from_request(request)?;
+    let tables = execute_query(pool, er_diagram::ER_DIAGRAM_TABLES_QUERY).await?;
+    let tables =
+        serde_json::from_value(tables).map_err(|err| ServerError::Internal(err.to_string()))?;
+    let statement = sql::build_sql_query(
+        &er_diagram::get_foreign_keys_request(),
+        configuration::get_catalog_backend(),
+    )?
+    .to_string();
+    let foreign_keys = er_diagram::get_foreign_keys(&execute_query(pool, &statement).await?)?;
+    let diagram = er_diagram::get_er_diagram(tables, &foreign_keys, &arguments)?;
+    get_command_response(request, er_diagram::ER_DIAGRAM, &diagram)
+}
+
+// gets the response of a command: a single row, with the result of the command in its
+// __value field
+fn get_command_response<T: serde::Serialize>(
+    request: &QueryRequest,
+    command: &str,
+    result: &T,
+) -> Result<Json<QueryResponse>, ServerError> {
+    let value =
+        serde_json::to_value(result).map_err(|err| ServerError::Internal(err.to_string()))?;
+    let mut row = serde_json::Map::new();
+    for (alias, field) in request.query.fields.clone().unwrap_or_default() {
+        match field {
+            models::Field::Column { column, .. } if column == commands::COMMAND_VALUE_FIELD => {
+                row.insert(alias, serde_json::json!({ "value": value.clone() }));
+            }
+            _ => {
+                return Err(ServerError::BadRequest(format!(
+                    "{} only has the {} field",
+                    command,
+                    commands::COMMAND_VALUE_FIELD
+                )))
+            }
+        }
+    }
+    serde_json::from_value(serde_json::json!([{ "rows": [row] }]))
+        .map(Json)
+        .map_err(|err| ServerError::Internal(err.to_string()))
+}
+
+// gets the URL of a database argument, which is the name of one of the SOURCES, or a URL when
+// ALLOW_SOURCE_URLS is set
+fn get_source_url(
+    request: &QueryRequest,
+    vars: &[HashMap<String, serde_json::Value>],
+    argument: &str,
+) -> Result<String, ServerError> {
+    match get_argument_value(&request.arguments, vars, argument) {
+        Some(serde_json::Value::String(source))
+            if source.starts_with("postgres://") || source.starts_with("postgresql://") =>
+        {
+            if configuration::get_allow_source_urls() {
+                Ok(source.clone())
+            } else {
+                Err(ServerError::BadRequest(format!(
+                    "{} should be a source name; database URLs are only accepted with ALLOW_SOURCE_URLS=true",
+                    argument
+                )))
+            }
+        }
+        Some(serde_json::Value::String(source)) => configuration::get_sources()
+            .remove(source)
+            .ok_or_else(|| ServerError::BadRequest(format!("unknown source {}", source))),
+        _ => Err(ServerError::BadRequest(format!(
+            "{} should be a source name or a database URL",
+            argument
+        ))),
+    }
+}
+
+// reads the tables, columns, constraints and indexes of a database to compare
+async fn get_schema_objects(
+    argument: &str,
+    db_url: &String,
+) -> Result<Vec<schema_diff::SchemaObject>, ServerError> {
+    let pool = get_sql_connection_pool(db_url).await.map_err(|err| {
+        ServerError::BadRequest(format!(
+            "could not connect to the {} database: {}",
+            argument, err
+        ))
+    })?;
+    let objects = execute_query(&pool, schema_diff::SCHEMA_OBJECTS_QUERY).await?;
+    serde_json::from_value(objects).map_err(|err| ServerError::Internal(err.to_string()))
+}
+
+// executes the SQL statement and gets the JSON response it builds
+async fn execute_query(pool: &PgPool, statement: &str) -> Result<serde_json::Value, ServerError> {
+    let result: PgRow = sqlx::query(statement).fetch_one(pool).await?;
+    result
+        .try_get(0)
+        .map_err(|err| ServerError::DatabaseError(err.to_string()))
 }
 
 // gets the PG connection pool to execute upon
@@ -88,28 +297,3 @@ async fn get_sql_connection_pool(db_url: &String) -> Result<PgPool, sqlx::Error>
         .connect(db_url)
         .await
 }
-
-// get's an argument value from arguments and variables provided in the request
-// the borrowing can be improved
-fn get_argument_value<'a>(
-    arguments: &'a HashMap<String, Argument>,
-    variables: &'a Vec<HashMap<String, serde_json::Value>>,
-    key: String,
-) -> Option<&'a serde_json::Value> {
-    let argument = arguments.get(key.as_str());
-
-    match argument {
-        Some(arg) => match arg {
-            Argument::Variable { name } => {
-                let val = variables
-                    .iter()
-                    .find(|p| p.get(name).is_some())
-                    .map(|vars| vars.get(name))
-                    .and_then(|v| v);
-                val
-            }
-            Argument::Literal { value } => Some(value),
-        },
-        None => None,
-    }
-}
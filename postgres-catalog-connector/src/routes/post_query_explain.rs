@@ -1,5 +1,5 @@
 use axum::Json;
-use cc_postgres::sql;
+use cc_postgres::{configuration, sql};
 
 use cc_postgres::error::ServerError;
 use ndc_client::models::{ExplainResponse, QueryRequest};
@@ -12,7 +12,7 @@ pub async fn handler(
 ) -> Result<Json<ExplainResponse>, ServerError> {
     println!("received query explain request");
 
-    let query = sql::build_sql_query(&request);
+    let query = sql::build_sql_query(&request, configuration::get_catalog_backend());
     let built_query = match query {
         Ok(q) => q,
         Err(err) => return Err(err),
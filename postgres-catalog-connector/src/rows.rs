@@ -0,0 +1,131 @@
+use serde_json::{Map, Value};
+
+use crate::error::ServerError;
+
+// gets the rows of the response to a query request, with the values of their fields unwrapped
+pub fn get_response_rows(response: &Value) -> Result<Vec<Map<String, Value>>, ServerError> {
+    match response.get(0).and_then(|r| r.get("rows")) {
+        Some(Value::Array(rows)) => rows
+            .iter()
+            .map(|row| match row {
+                Value::Object(fields) => Ok(fields
+                    .iter()
+                    .map(|(column, field)| (column.clone(), field["value"].clone()))
+                    .collect()),
+                _ => Err(ServerError::Internal(
+                    "unexpected row from the database".into(),
+                )),
+            })
+            .collect(),
+        _ => Err(ServerError::Internal(
+            "unexpected response from the database".into(),
+        )),
+    }
+}
+
+// matches a value against a LIKE pattern, where % matches any characters, _ matches one
+// character and \ escapes the character after it
+pub fn is_like(value: &Value, pattern: &Value, case_insensitive: bool) -> bool {
+    let get_chars = |value: &Value| -> Vec<char> {
+        let text = match value {
+            Value::String(s) => s.clone(),
+            _ => value.to_string(),
+        };
+        if case_insensitive {
+            text.to_lowercase().chars().collect()
+        } else {
+            text.chars().collect()
+        }
+    };
+    is_like_match(&get_chars(value), &get_chars(pattern))
+}
+
+enum LikeToken {
+    // %
+    AnyCharacters,
+    // _
+    AnyCharacter,
+    Character(char),
+}
+
+fn get_like_tokens(pattern: &[char]) -> Vec<LikeToken> {
+    let mut tokens = vec![];
+    let mut characters = pattern.iter();
+    while let Some(c) = characters.next() {
+        tokens.push(match c {
+            '%' => LikeToken::AnyCharacters,
+            '_' => LikeToken::AnyCharacter,
+            // a \ at the end of the pattern matches itself
+            '\\' => LikeToken::Character(*characters.next().unwrap_or(&'\\')),
+            c => LikeToken::Character(*c),
+        });
+    }
+    tokens
+}
+
+// matches the value against the pattern from left to right. When a character does not match,
+// the last % takes one more character of the value and the match goes on after it; the %s
+// before it never need to take more, which keeps the match linear in the pattern for every
+// position of the value.
+fn is_like_match(value: &[char], pattern: &[char]) -> bool {
+    let tokens = get_like_tokens(pattern);
+    let (mut v, mut t) = (0, 0);
+    // the token after the last %, and the position in the value it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+    while v < value.len() {
+        match tokens.get(t) {
+            Some(LikeToken::AnyCharacters) => {
+                t += 1;
+                backtrack = Some((t, v));
+            }
+            Some(LikeToken::AnyCharacter) => {
+                v += 1;
+                t += 1;
+            }
+            Some(LikeToken::Character(c)) if *c == value[v] => {
+                v += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((after_any, tried_at)) => {
+                    t = after_any;
+                    v = tried_at + 1;
+                    backtrack = Some((after_any, v));
+                }
+                None => return false,
+            },
+        }
+    }
+    tokens[t..]
+        .iter()
+        .all(|token| matches!(token, LikeToken::AnyCharacters))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn like_matches_like_postgres() {
+        let is_match = |value: &str, pattern: &str| is_like(&json!(value), &json!(pattern), false);
+        assert!(is_match("orders", "orders"));
+        assert!(is_match("orders", "%"));
+        assert!(is_match("orders", "o%s"));
+        assert!(is_match("orders", "_rd%"));
+        assert!(is_match("a%b", "a\\%b"));
+        assert!(is_match("ends with \\", "%\\"));
+        assert!(is_match("abcabd", "%ab_"));
+        assert!(!is_match("orders", "order"));
+        assert!(!is_match("axb", "a\\%b"));
+        assert!(!is_match("", "_"));
+        assert!(is_like(&json!("Orders"), &json!("orders"), true));
+    }
+
+    #[test]
+    fn like_does_not_backtrack_exponentially() {
+        let value = "a".repeat(10_000);
+        let pattern = format!("{}b", "%a".repeat(50));
+        assert!(!is_like(&json!(value), &json!(pattern), false));
+    }
+}
@@ -0,0 +1,764 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+// the name of the command that compares the schemas of two databases
+pub const SCHEMA_DIFF: &str = "schema_diff";
+
+// The tables, columns, constraints and indexes that are compared, as a JSON array. Partitions
+// and the tables of extensions are left out, and so are the indexes that back a constraint,
+// since those are compared as constraints. The properties of an object are the ones that make
+// it differ from the object of the same name in the other database.
+pub const SCHEMA_OBJECTS_QUERY: &str = "
+WITH diff_tables AS (
+    SELECT c.oid, n.nspname, c.relname, c.relkind
+    FROM pg_class AS c
+    JOIN pg_namespace AS n ON n.oid = c.relnamespace
+    WHERE c.relkind IN ('r', 'p')
+        AND NOT c.relispartition
+        AND n.nspname NOT LIKE 'pg\\_%'
+        AND n.nspname <> 'information_schema'
+        AND NOT EXISTS (
+            SELECT 1
+            FROM pg_depend AS d
+            WHERE d.classid = 'pg_class'::regclass AND d.objid = c.oid AND d.deptype = 'e'
+        )
+)
+SELECT coalesce(json_agg(o), '[]')
+FROM (
+    SELECT
+        'table' AS object_type,
+        t.nspname AS table_schema,
+        t.relname AS table_name,
+        t.relname AS object_name,
+        quote_ident(t.nspname) AS quoted_table_schema,
+        format('%I.%I', t.nspname, t.relname) AS quoted_table_name,
+        quote_ident(t.relname) AS quoted_object_name,
+        0 AS position,
+        json_build_object(
+            'table_type', CASE WHEN t.relkind = 'p' THEN 'PARTITIONED TABLE' ELSE 'BASE TABLE' END,
+            'partition_key', pg_get_partkeydef(t.oid)
+        ) AS properties
+    FROM diff_tables AS t
+    UNION ALL
+    SELECT
+        'column',
+        t.nspname,
+        t.relname,
+        a.attname,
+        quote_ident(t.nspname),
+        format('%I.%I', t.nspname, t.relname),
+        quote_ident(a.attname),
+        a.attnum,
+        json_build_object(
+            'data_type', format_type(a.atttypid, a.atttypmod),
+            'collation', (
+                SELECT format('%I.%I', cn.nspname, co.collname)
+                FROM pg_collation AS co
+                JOIN pg_namespace AS cn ON cn.oid = co.collnamespace
+                JOIN pg_type AS ty ON ty.oid = a.atttypid
+                WHERE co.oid = a.attcollation AND a.attcollation <> ty.typcollation
+            ),
+            'is_nullable', CASE WHEN a.attnotnull THEN 'NO' ELSE 'YES' END,
+            'column_default', CASE WHEN a.attgenerated = '' THEN pg_get_expr(d.adbin, d.adrelid) END,
+            'identity', CASE a.attidentity WHEN 'a' THEN 'ALWAYS' WHEN 'd' THEN 'BY DEFAULT' END,
+            'generation_expression', CASE WHEN a.attgenerated = 's' THEN pg_get_expr(d.adbin, d.adrelid) END
+        )
+    FROM diff_tables AS t
+    JOIN pg_attribute AS a ON a.attrelid = t.oid
+    LEFT JOIN pg_attrdef AS d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+    WHERE a.attnum > 0 AND NOT a.attisdropped
+    UNION ALL
+    SELECT
+        'constraint',
+        t.nspname,
+        t.relname,
+        k.conname,
+        quote_ident(t.nspname),
+        format('%I.%I', t.nspname, t.relname),
+        quote_ident(k.conname),
+        0,
+        json_build_object(
+            'constraint_type', CASE k.contype
+                WHEN 'p' THEN 'PRIMARY KEY'
+                WHEN 'u' THEN 'UNIQUE'
+                WHEN 'c' THEN 'CHECK'
+                WHEN 'f' THEN 'FOREIGN KEY'
+                ELSE 'EXCLUDE'
+            END,
+            'definition', pg_get_constraintdef(k.oid)
+        )
+    FROM diff_tables AS t
+    JOIN pg_constraint AS k ON k.conrelid = t.oid
+    WHERE k.contype IN ('p', 'u', 'c', 'f', 'x') AND k.conislocal
+    UNION ALL
+    SELECT
+        'index',
+        t.nspname,
+        t.relname,
+        ic.relname,
+        quote_ident(t.nspname),
+        format('%I.%I', t.nspname, t.relname),
+        format('%I.%I', t.nspname, ic.relname),
+        0,
+        json_build_object(
+            'definition', replace(pg_get_indexdef(i.indexrelid), ' ON ONLY ', ' ON ')
+        )
+    FROM diff_tables AS t
+    JOIN pg_index AS i ON i.indrelid = t.oid
+    JOIN pg_class AS ic ON ic.oid = i.indexrelid
+    WHERE NOT EXISTS (
+        SELECT 1
+        FROM pg_constraint AS k
+        WHERE k.conindid = i.indexrelid AND k.contype IN ('p', 'u', 'x')
+    )
+) AS o
+";
+
+// the kinds of objects that are compared, in the order they are listed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaObjectType {
+    Table,
+    Column,
+    Constraint,
+    Index,
+}
+
+// a table, column, constraint or index read with SCHEMA_OBJECTS_QUERY
+#[derive(Debug, Clone, Deserialize)]
+pub struct SchemaObject {
+    pub object_type: SchemaObjectType,
+    pub table_schema: String,
+    pub table_name: String,
+    pub object_name: String,
+    pub quoted_table_schema: String,
+    pub quoted_table_name: String,
+    pub quoted_object_name: String,
+    // the position of a column in its table
+    pub position: i32,
+    pub properties: BTreeMap<String, Option<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaChange {
+    Added,
+    Removed,
+    Changed,
+}
+
+// a difference between the two schemas. Added and removed objects have their definition as
+// after and before; changed objects have a difference for every property that changed.
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaDifference {
+    pub change: SchemaChange,
+    pub object_type: SchemaObjectType,
+    pub table_schema: String,
+    pub table_name: String,
+    pub object_name: String,
+    pub property: Option<String>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaDiff {
+    pub differences: Vec<SchemaDifference>,
+    pub migration_script: Option<String>,
+}
+
+type SchemaObjectKey = (String, String, SchemaObjectType, String);
+
+fn get_schema_object_key(object: &SchemaObject) -> SchemaObjectKey {
+    (
+        object.table_schema.clone(),
+        object.table_name.clone(),
+        object.object_type,
+        object.object_name.clone(),
+    )
+}
+
+// compares the objects of the schema migrated from with the ones of the schema migrated to
+pub fn get_schema_diff(
+    from: Vec<SchemaObject>,
+    to: Vec<SchemaObject>,
+    include_migration_script: bool,
+) -> SchemaDiff {
+    let from: BTreeMap<SchemaObjectKey, SchemaObject> = from
+        .into_iter()
+        .map(|object| (get_schema_object_key(&object), object))
+        .collect();
+    let to: BTreeMap<SchemaObjectKey, SchemaObject> = to
+        .into_iter()
+        .map(|object| (get_schema_object_key(&object), object))
+        .collect();
+    let keys: BTreeSet<&SchemaObjectKey> = from.keys().chain(to.keys()).collect();
+
+    let mut differences = vec![];
+    let mut migration = Migration::default();
+    for key in keys {
+        match (from.get(key), to.get(key)) {
+            (Some(before), None) => {
+                differences.push(get_difference(
+                    SchemaChange::Removed,
+                    before,
+                    None,
+                    get_definition(before),
+                    None,
+                ));
+                migration.remove(before, &to);
+            }
+            (None, Some(after)) => {
+                differences.push(get_difference(
+                    SchemaChange::Added,
+                    after,
+                    None,
+                    None,
+                    get_definition(after),
+                ));
+                migration.add(after, &from, &to);
+            }
+            (Some(before), Some(after)) => {
+                let properties: BTreeSet<&String> = before
+                    .properties
+                    .keys()
+                    .chain(after.properties.keys())
+                    .collect();
+                let mut changed = vec![];
+                for property in properties {
+                    let before_value = before.properties.get(property).cloned().flatten();
+                    let after_value = after.properties.get(property).cloned().flatten();
+                    if before_value != after_value {
+                        differences.push(get_difference(
+                            SchemaChange::Changed,
+                            after,
+                            Some(property.clone()),
+                            before_value,
+                            after_value,
+                        ));
+                        changed.push(property.as_str());
+                    }
+                }
+                if !changed.is_empty() {
+                    migration.change(before, after, &changed);
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    SchemaDiff {
+        differences,
+        migration_script: include_migration_script.then(|| migration.get_script()),
+    }
+}
+
+fn get_difference(
+    change: SchemaChange,
+    object: &SchemaObject,
+    property: Option<String>,
+    before: Option<String>,
+    after: Option<String>,
+) -> SchemaDifference {
+    SchemaDifference {
+        change,
+        object_type: object.object_type,
+        table_schema: object.table_schema.clone(),
+        table_name: object.table_name.clone(),
+        object_name: object.object_name.clone(),
+        property,
+        before,
+        after,
+    }
+}
+
+fn get_property<'a>(object: &'a SchemaObject, property: &str) -> Option<&'a str> {
+    object
+        .properties
+        .get(property)
+        .and_then(|value| value.as_deref())
+}
+
+// the definition of an object as it is written in a CREATE TABLE statement
+fn get_definition(object: &SchemaObject) -> Option<String> {
+    match object.object_type {
+        SchemaObjectType::Table => get_property(object, "table_type").map(String::from),
+        SchemaObjectType::Column => Some(get_column_definition(object)),
+        SchemaObjectType::Constraint => get_property(object, "definition").map(String::from),
+        SchemaObjectType::Index => get_property(object, "definition").map(String::from),
+    }
+}
+
+fn get_column_definition(column: &SchemaObject) -> String {
+    let mut definition = format!(
+        "{} {}",
+        column.quoted_object_name,
+        get_property(column, "data_type").unwrap_or_default()
+    );
+    if let Some(collation) = get_property(column, "collation") {
+        definition.push_str(&format!(" COLLATE {}", collation));
+    }
+    if let Some(expression) = get_property(column, "generation_expression") {
+        definition.push_str(&format!(" GENERATED ALWAYS AS ({}) STORED", expression));
+    } else if let Some(identity) = get_property(column, "identity") {
+        definition.push_str(&format!(" GENERATED {} AS IDENTITY", identity));
+    } else if let Some(default) = get_property(column, "column_default") {
+        definition.push_str(&format!(" DEFAULT {}", default));
+    }
+    if get_property(column, "is_nullable") == Some("NO") {
+        definition.push_str(" NOT NULL");
+    }
+    definition
+}
+
+// the steps of the migration script, in the order they run in: foreign keys are dropped before
+// the constraints, indexes and tables they depend on, and added after all of them, since they
+// can reference the tables and unique indexes of other steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MigrationStep {
+    DropForeignKeys,
+    DropConstraints,
+    DropIndexes,
+    DropColumns,
+    DropTables,
+    CreateSchemas,
+    CreateTables,
+    AlterColumns,
+    AddConstraints,
+    CreateIndexes,
+    AddForeignKeys,
+}
+
+#[derive(Debug, Default)]
+struct Migration {
+    statements: Vec<(MigrationStep, String)>,
+}
+
+impl Migration {
+    fn push(&mut self, step: MigrationStep, statement: String) {
+        self.statements.push((step, statement));
+    }
+
+    fn remove(&mut self, object: &SchemaObject, to: &BTreeMap<SchemaObjectKey, SchemaObject>) {
+        // the columns, constraints and indexes of a removed table are dropped with it
+        if object.object_type != SchemaObjectType::Table && !has_table(object, to) {
+            return;
+        }
+        match object.object_type {
+            SchemaObjectType::Table => self.push(
+                MigrationStep::DropTables,
+                format!("DROP TABLE {};", object.quoted_table_name),
+            ),
+            SchemaObjectType::Column => self.push(
+                MigrationStep::DropColumns,
+                format!(
+                    "ALTER TABLE {} DROP COLUMN {};",
+                    object.quoted_table_name, object.quoted_object_name
+                ),
+            ),
+            SchemaObjectType::Constraint => self.drop_constraint(object),
+            SchemaObjectType::Index => self.push(
+                MigrationStep::DropIndexes,
+                format!("DROP INDEX {};", object.quoted_object_name),
+            ),
+        }
+    }
+
+    fn add(
+        &mut self,
+        object: &SchemaObject,
+        from: &BTreeMap<SchemaObjectKey, SchemaObject>,
+        to: &BTreeMap<SchemaObjectKey, SchemaObject>,
+    ) {
+        match object.object_type {
+            // a new table is created with its columns, since a partitioned table needs the
+            // columns of its partition key
+            SchemaObjectType::Table => {
+                let mut columns: Vec<&SchemaObject> = to
+                    .values()
+                    .filter(|o| {
+                        o.object_type == SchemaObjectType::Column
+                            && o.table_schema == object.table_schema
+                            && o.table_name == object.table_name
+                    })
+                    .collect();
+                columns.sort_by_key(|column| column.position);
+                let columns: Vec<String> = columns
+                    .into_iter()
+                    .map(|column| format!("    {}", get_column_definition(column)))
+                    .collect();
+                // the schema of a new table may be new as well. It can also be there without
+                // any tables in it, so it is only created when it does not exist.
+                if !from.values().any(|o| o.table_schema == object.table_schema) {
+                    let create_schema = format!(
+                        "CREATE SCHEMA IF NOT EXISTS {};",
+                        object.quoted_table_schema
+                    );
+                    if !self.statements.iter().any(|(_, s)| *s == create_schema) {
+                        self.push(MigrationStep::CreateSchemas, create_schema);
+                    }
+                }
+                let partition_key = match get_property(object, "partition_key") {
+                    Some(key) => format!(" PARTITION BY {}", key),
+                    None => String::new(),
+                };
+                self.push(
+                    MigrationStep::CreateTables,
+                    format!(
+                        "CREATE TABLE {} (\n{}\n){};",
+                        object.quoted_table_name,
+                        columns.join(",\n"),
+                        partition_key
+                    ),
+                );
+            }
+            SchemaObjectType::Column => {
+                if has_table(object, from) {
+                    self.push(
+                        MigrationStep::AlterColumns,
+                        format!(
+                            "ALTER TABLE {} ADD COLUMN {};",
+                            object.quoted_table_name,
+                            get_column_definition(object)
+                        ),
+                    );
+                }
+            }
+            SchemaObjectType::Constraint => self.add_constraint(object),
+            SchemaObjectType::Index => self.push(
+                MigrationStep::CreateIndexes,
+                format!(
+                    "{};",
+                    get_property(object, "definition").unwrap_or_default()
+                ),
+            ),
+        }
+    }
+
+    fn change(&mut self, before: &SchemaObject, after: &SchemaObject, properties: &[&str]) {
+        match after.object_type {
+            SchemaObjectType::Table => {
+                for property in properties {
+                    self.push_manual_step(after, property);
+                }
+            }
+            SchemaObjectType::Column => {
+                let alter_column = format!(
+                    "ALTER TABLE {} ALTER COLUMN {}",
+                    after.quoted_table_name, after.quoted_object_name
+                );
+                for property in properties {
+                    let statement = match (*property, get_property(after, property)) {
+                        ("data_type", Some(data_type)) => {
+                            format!("{} TYPE {};", alter_column, data_type)
+                        }
+                        ("column_default", Some(default)) => {
+                            format!("{} SET DEFAULT {};", alter_column, default)
+                        }
+                        ("column_default", None) => format!("{} DROP DEFAULT;", alter_column),
+                        ("is_nullable", Some("NO")) => format!("{} SET NOT NULL;", alter_column),
+                        ("is_nullable", _) => format!("{} DROP NOT NULL;", alter_column),
+                        _ => {
+                            self.push_manual_step(after, property);
+                            continue;
+                        }
+                    };
+                    self.push(MigrationStep::AlterColumns, statement);
+                }
+            }
+            SchemaObjectType::Constraint => {
+                self.drop_constraint(before);
+                self.add_constraint(after);
+            }
+            SchemaObjectType::Index => {
+                self.push(
+                    MigrationStep::DropIndexes,
+                    format!("DROP INDEX {};", before.quoted_object_name),
+                );
+                self.push(
+                    MigrationStep::CreateIndexes,
+                    format!("{};", get_property(after, "definition").unwrap_or_default()),
+                );
+            }
+        }
+    }
+
+    fn drop_constraint(&mut self, constraint: &SchemaObject) {
+        self.push(
+            if get_property(constraint, "constraint_type") == Some("FOREIGN KEY") {
+                MigrationStep::DropForeignKeys
+            } else {
+                MigrationStep::DropConstraints
+            },
+            format!(
+                "ALTER TABLE {} DROP CONSTRAINT {};",
+                constraint.quoted_table_name, constraint.quoted_object_name
+            ),
+        );
+    }
+
+    fn add_constraint(&mut self, constraint: &SchemaObject) {
+        self.push(
+            if get_property(constraint, "constraint_type") == Some("FOREIGN KEY") {
+                MigrationStep::AddForeignKeys
+            } else {
+                MigrationStep::AddConstraints
+            },
+            format!(
+                "ALTER TABLE {} ADD CONSTRAINT {} {};",
+                constraint.quoted_table_name,
+                constraint.quoted_object_name,
+                get_property(constraint, "definition").unwrap_or_default()
+            ),
+        );
+    }
+
+    // changes that cannot be made with a single statement, like turning a column into an
+    // identity column, are left as a comment in the script
+    fn push_manual_step(&mut self, object: &SchemaObject, property: &str) {
+        self.push(
+            MigrationStep::AlterColumns,
+            format!(
+                "-- {}.{}: {} changed to {}, which has to be migrated by hand",
+                object.quoted_table_name,
+                object.quoted_object_name,
+                property,
+                get_property(object, property).unwrap_or("null")
+            ),
+        );
+    }
+
+    fn get_script(mut self) -> String {
+        // the sort is stable, so the statements of a step stay in the order of the objects
+        self.statements.sort_by_key(|(step, _)| *step);
+        let mut script = vec!["BEGIN;".to_string()];
+        script.extend(self.statements.into_iter().map(|(_, statement)| statement));
+        script.push("COMMIT;".into());
+        script.join("\n")
+    }
+}
+
+fn has_table(object: &SchemaObject, objects: &BTreeMap<SchemaObjectKey, SchemaObject>) -> bool {
+    objects.contains_key(&(
+        object.table_schema.clone(),
+        object.table_name.clone(),
+        SchemaObjectType::Table,
+        object.table_name.clone(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_object(
+        object_type: SchemaObjectType,
+        table: (&str, &str),
+        object_name: &str,
+        properties: &[(&str, &str)],
+    ) -> SchemaObject {
+        let (table_schema, table_name) = table;
+        SchemaObject {
+            object_type,
+            table_schema: table_schema.into(),
+            table_name: table_name.into(),
+            object_name: object_name.into(),
+            quoted_table_schema: table_schema.into(),
+            quoted_table_name: format!("{}.{}", table_schema, table_name),
+            quoted_object_name: object_name.into(),
+            position: 1,
+            properties: properties
+                .iter()
+                .map(|(name, value)| (name.to_string(), Some(value.to_string())))
+                .collect(),
+        }
+    }
+
+    fn get_table(table: (&str, &str)) -> SchemaObject {
+        get_object(
+            SchemaObjectType::Table,
+            table,
+            table.1,
+            &[("table_type", "BASE TABLE")],
+        )
+    }
+
+    fn get_column(table: (&str, &str), name: &str, data_type: &str) -> SchemaObject {
+        get_object(
+            SchemaObjectType::Column,
+            table,
+            name,
+            &[("data_type", data_type), ("is_nullable", "NO")],
+        )
+    }
+
+    fn get_script_lines(diff: &SchemaDiff) -> Vec<&str> {
+        diff.migration_script
+            .as_deref()
+            .expect("the diff should have a migration script")
+            .lines()
+            .collect()
+    }
+
+    fn get_line(lines: &[&str], prefix: &str) -> usize {
+        lines
+            .iter()
+            .position(|line| line.starts_with(prefix))
+            .unwrap_or_else(|| panic!("the script should have a line starting with {}", prefix))
+    }
+
+    #[test]
+    fn differences_are_listed_by_property() {
+        let from = vec![
+            get_table(("public", "orders")),
+            get_column(("public", "orders"), "id", "integer"),
+            get_column(("public", "orders"), "note", "text"),
+        ];
+        let to = vec![
+            get_table(("public", "orders")),
+            get_column(("public", "orders"), "id", "bigint"),
+        ];
+        let diff = get_schema_diff(from, to, false);
+        assert!(diff.migration_script.is_none());
+        assert_eq!(diff.differences.len(), 2);
+        let changed = &diff.differences[0];
+        assert_eq!(changed.change, SchemaChange::Changed);
+        assert_eq!(changed.property.as_deref(), Some("data_type"));
+        assert_eq!(changed.before.as_deref(), Some("integer"));
+        assert_eq!(changed.after.as_deref(), Some("bigint"));
+        let removed = &diff.differences[1];
+        assert_eq!(removed.change, SchemaChange::Removed);
+        assert_eq!(removed.object_name, "note");
+        assert_eq!(removed.before.as_deref(), Some("note text NOT NULL"));
+    }
+
+    #[test]
+    fn equal_schemas_have_no_differences() {
+        let objects = vec![
+            get_table(("public", "orders")),
+            get_column(("public", "orders"), "id", "integer"),
+        ];
+        let diff = get_schema_diff(objects.clone(), objects, true);
+        assert!(diff.differences.is_empty());
+        assert_eq!(get_script_lines(&diff), vec!["BEGIN;", "COMMIT;"]);
+    }
+
+    #[test]
+    fn new_schemas_are_created_before_their_tables() {
+        let from = vec![get_table(("public", "orders"))];
+        let to = vec![
+            get_table(("public", "orders")),
+            get_table(("billing", "invoices")),
+            get_column(("billing", "invoices"), "id", "integer"),
+            get_table(("billing", "payments")),
+            get_table(("public", "customers")),
+        ];
+        let diff = get_schema_diff(from, to, true);
+        let lines = get_script_lines(&diff);
+        let create_schema = get_line(&lines, "CREATE SCHEMA IF NOT EXISTS billing;");
+        assert!(create_schema < get_line(&lines, "CREATE TABLE billing.invoices"));
+        assert!(create_schema < get_line(&lines, "CREATE TABLE billing.payments"));
+        // the schema is created once, and public is not created at all
+        assert_eq!(
+            lines
+                .iter()
+                .filter(|line| line.starts_with("CREATE SCHEMA"))
+                .count(),
+            1
+        );
+        assert!(lines.contains(&"    id integer NOT NULL"));
+    }
+
+    #[test]
+    fn foreign_keys_are_added_after_the_tables_and_indexes_they_reference() {
+        let from = vec![get_table(("public", "orders"))];
+        let to =
+            vec![
+            get_table(("public", "orders")),
+            get_object(
+                SchemaObjectType::Constraint,
+                ("public", "orders"),
+                "orders_customer_fkey",
+                &[
+                    ("constraint_type", "FOREIGN KEY"),
+                    ("definition", "FOREIGN KEY (customer) REFERENCES public.customers(email)"),
+                ],
+            ),
+            get_table(("public", "customers")),
+            get_object(
+                SchemaObjectType::Index,
+                ("public", "customers"),
+                "customers_email",
+                &[(
+                    "definition",
+                    "CREATE UNIQUE INDEX customers_email ON public.customers USING btree (email)",
+                )],
+            ),
+            get_object(
+                SchemaObjectType::Constraint,
+                ("public", "customers"),
+                "customers_pkey",
+                &[
+                    ("constraint_type", "PRIMARY KEY"),
+                    ("definition", "PRIMARY KEY (id)"),
+                ],
+            ),
+        ];
+        let diff = get_schema_diff(from, to, true);
+        let lines = get_script_lines(&diff);
+        let add_foreign_key = get_line(&lines, "ALTER TABLE public.orders ADD CONSTRAINT");
+        assert!(get_line(&lines, "CREATE TABLE public.customers") < add_foreign_key);
+        assert!(get_line(&lines, "CREATE UNIQUE INDEX customers_email") < add_foreign_key);
+        assert!(get_line(&lines, "ALTER TABLE public.customers ADD CONSTRAINT") < add_foreign_key);
+        assert_eq!(lines.last(), Some(&"COMMIT;"));
+    }
+
+    #[test]
+    fn removed_tables_are_dropped_with_their_objects() {
+        let from = vec![
+            get_table(("public", "orders")),
+            get_column(("public", "orders"), "id", "integer"),
+            get_object(
+                SchemaObjectType::Constraint,
+                ("public", "orders"),
+                "orders_customer_fkey",
+                &[
+                    ("constraint_type", "FOREIGN KEY"),
+                    (
+                        "definition",
+                        "FOREIGN KEY (customer) REFERENCES public.customers(id)",
+                    ),
+                ],
+            ),
+        ];
+        let diff = get_schema_diff(from, vec![], true);
+        assert_eq!(diff.differences.len(), 3);
+        assert_eq!(
+            get_script_lines(&diff),
+            vec!["BEGIN;", "DROP TABLE public.orders;", "COMMIT;"]
+        );
+    }
+
+    #[test]
+    fn changed_columns_are_altered() {
+        let from = vec![
+            get_table(("public", "orders")),
+            get_column(("public", "orders"), "id", "integer"),
+        ];
+        let mut column = get_column(("public", "orders"), "id", "bigint");
+        column
+            .properties
+            .insert("is_nullable".into(), Some("YES".into()));
+        column
+            .properties
+            .insert("identity".into(), Some("ALWAYS".into()));
+        let to = vec![get_table(("public", "orders")), column];
+        let diff = get_schema_diff(from, to, true);
+        let lines = get_script_lines(&diff);
+        assert!(lines.contains(&"ALTER TABLE public.orders ALTER COLUMN id TYPE bigint;"));
+        assert!(lines.contains(&"ALTER TABLE public.orders ALTER COLUMN id DROP NOT NULL;"));
+        assert!(lines.contains(
+            &"-- public.orders.id: identity changed to ALWAYS, which has to be migrated by hand"
+        ));
+    }
+}
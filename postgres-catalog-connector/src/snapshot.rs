@@ -0,0 +1,657 @@
+use ndc_client::models::{self, Expression, QueryRequest};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::arguments::{TableArguments, MAX_DEPENDENCY_DEPTH};
+use crate::configuration;
+use crate::error::ServerError;
+use crate::fan_out::compare_values;
+use crate::rows::{get_response_rows, is_like};
+use crate::sql::get_query_with_arguments;
+use crate::tables::{SupportedTable, DATABASE_NAME_COLUMN, SUPPORTED_TABLES};
+
+// the name of the command that serializes the catalog of a database into a snapshot
+pub const SNAPSHOT: &str = "snapshot";
+
+// the version of the snapshot document; bumped when a snapshot can no longer be read the same way
+pub const SNAPSHOT_VERSION: u64 = 1;
+
+// database URLs with this prefix name a snapshot file of SNAPSHOT_DIR instead of a database,
+// like snapshot:production for $SNAPSHOT_DIR/production.json
+pub const SNAPSHOT_URL_PREFIX: &str = "snapshot:";
+
+// the extension of the snapshot files in SNAPSHOT_DIR
+const SNAPSHOT_FILE_EXTENSION: &str = "json";
+
+// what the snapshot records about the database it was taken of
+pub const SNAPSHOT_INFO_QUERY: &str = "
+SELECT json_build_object(
+    'created_at', now(),
+    'database', current_database(),
+    'server_version', current_setting('server_version')
+)
+";
+
+// the catalog of a database: every row of every table supported by this data connector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub version: u64,
+    pub created_at: String,
+    pub database: String,
+    pub server_version: String,
+    // the rows of every table, by table name. The columns of a row are the columns of the table.
+    pub collections: BTreeMap<String, Vec<Map<String, Value>>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SnapshotInfo {
+    created_at: String,
+    database: String,
+    server_version: String,
+}
+
+// gets the name of the snapshot a database URL points to, if it points to one
+pub fn get_snapshot_name(db_url: &str) -> Option<&str> {
+    db_url.strip_prefix(SNAPSHOT_URL_PREFIX)
+}
+
+// gets the path of a snapshot file from its name. Requests only name snapshots, so that they
+// cannot read any other file: the name cannot be a path, and the file it resolves to, after
+// following symbolic links, has to be in the snapshot directory.
+fn get_snapshot_path(snapshot_dir: Option<&str>, name: &str) -> Result<PathBuf, ServerError> {
+    let snapshot_dir = snapshot_dir.ok_or_else(|| {
+        ServerError::BadRequest("snapshots cannot be queried, SNAPSHOT_DIR is not set".into())
+    })?;
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        return Err(ServerError::BadRequest(format!(
+            "invalid snapshot name {}",
+            name
+        )));
+    }
+    let snapshot_dir = Path::new(snapshot_dir).canonicalize().map_err(|err| {
+        ServerError::Internal(format!("could not read the snapshot directory: {}", err))
+    })?;
+    let path = snapshot_dir
+        .join(format!("{}.{}", name, SNAPSHOT_FILE_EXTENSION))
+        .canonicalize()
+        .map_err(|_| ServerError::BadRequest(format!("unknown snapshot {}", name)))?;
+    if !path.starts_with(&snapshot_dir) {
+        return Err(ServerError::BadRequest(format!(
+            "unknown snapshot {}",
+            name
+        )));
+    }
+    Ok(path)
+}
+
+// gets the requests that read every row of every table for a snapshot. The dependencies are
+// walked as deep as they can be, so that the snapshot can answer any max_depth.
+pub fn get_snapshot_requests() -> Vec<QueryRequest> {
+    SUPPORTED_TABLES
+        .iter()
+        .map(|table_name| {
+            let table = SupportedTable::from_str(table_name).expect("table should be supported");
+            let fields = table
+                .get_columns()
+                .into_iter()
+                .map(|column| {
+                    (
+                        column.name.clone(),
+                        models::Field::Column {
+                            column: column.name,
+                            arguments: HashMap::new(),
+                        },
+                    )
+                })
+                .collect();
+            QueryRequest {
+                table: table_name.to_string(),
+                query: models::Query {
+                    aggregates: None,
+                    fields: Some(fields),
+                    limit: None,
+                    offset: None,
+                    order_by: None,
+                    predicate: None,
+                },
+                arguments: HashMap::from_iter([(
+                    "max_depth".into(),
+                    models::Argument::Literal {
+                        value: MAX_DEPENDENCY_DEPTH.into(),
+                    },
+                )]),
+                table_relationships: HashMap::new(),
+                variables: None,
+            }
+        })
+        .collect()
+}
+
+// builds the snapshot from the response of SNAPSHOT_INFO_QUERY and the responses of the
+// snapshot requests, by table name
+pub fn get_snapshot(info: Value, responses: Vec<(String, Value)>) -> Result<Snapshot, ServerError> {
+    let info: SnapshotInfo =
+        serde_json::from_value(info).map_err(|err| ServerError::Internal(err.to_string()))?;
+    let mut collections = BTreeMap::new();
+    for (table_name, response) in responses {
+        let mut rows = get_response_rows(&response)?;
+        // the catalog queries do not order their rows; sorting them keeps snapshots of the same
+        // catalog identical, so that they can be diffed
+        rows.sort_by_cached_key(|row| Value::Object(row.clone()).to_string());
+        collections.insert(table_name, rows);
+    }
+    Ok(Snapshot {
+        version: SNAPSHOT_VERSION,
+        created_at: info.created_at,
+        database: info.database,
+        server_version: info.server_version,
+        collections,
+    })
+}
+
+// reads the snapshot file of SNAPSHOT_DIR with the name, and checks that it is a snapshot of
+// the version this connector writes
+pub fn load_snapshot(name: &str) -> Result<Snapshot, ServerError> {
+    let path = get_snapshot_path(configuration::get_snapshot_dir().as_deref(), name)?;
+    let contents = std::fs::read_to_string(path).map_err(|err| {
+        ServerError::BadRequest(format!("could not read the snapshot {}: {}", name, err))
+    })?;
+    parse_snapshot(name, &contents)
+}
+
+fn parse_snapshot(name: &str, contents: &str) -> Result<Snapshot, ServerError> {
+    let document: Value = serde_json::from_str(contents).map_err(|err| {
+        ServerError::BadRequest(format!("could not parse the snapshot {}: {}", name, err))
+    })?;
+    match document.get("version").and_then(Value::as_u64) {
+        Some(SNAPSHOT_VERSION) => {}
+        Some(version) => {
+            return Err(ServerError::BadRequest(format!(
+                "{} is a version {} snapshot; this connector reads version {}",
+                name, version, SNAPSHOT_VERSION
+            )))
+        }
+        None => {
+            return Err(ServerError::BadRequest(format!(
+                "{} is not a snapshot",
+                name
+            )))
+        }
+    }
+    serde_json::from_value(document).map_err(|err| {
+        ServerError::BadRequest(format!("could not parse the snapshot {}: {}", name, err))
+    })
+}
+
+// answers a query request from a snapshot, with the same response the database would give:
+// the rows matching the predicate and the table arguments, ordered, paged and projected
+pub fn query_snapshot(snapshot: &Snapshot, request: &QueryRequest) -> Result<Value, ServerError> {
+    let table = SupportedTable::from_str(&request.table)
+        .map_err(|_| ServerError::BadRequest("unknown table".into()))?;
+    let query = get_query_with_arguments(request, &table);
+    let table_arguments = TableArguments::from_request(request)?;
+    let fields = match &query.fields {
+        Some(f) => f,
+        None => return Err(ServerError::BadRequest("fields must be present".into())),
+    };
+    let rows = snapshot.collections.get(&request.table).ok_or_else(|| {
+        ServerError::BadRequest(format!(
+            "the snapshot has no rows for the {} table",
+            request.table
+        ))
+    })?;
+    let context = SnapshotTable {
+        snapshot,
+        table: &table,
+    };
+
+    let mut matched = vec![];
+    for row in rows {
+        // the snapshot has the dependencies of every depth
+        if matches!(table, SupportedTable::Dependencies)
+            && matches!(
+                row.get("depth").and_then(Value::as_u64),
+                Some(depth) if depth > table_arguments.max_depth
+            )
+        {
+            continue;
+        }
+        let is_match = match &query.predicate {
+            Some(predicate) => context.evaluate(predicate, row)? == Some(true),
+            None => true,
+        };
+        if is_match {
+            matched.push(row);
+        }
+    }
+
+    let elements = match &query.order_by {
+        Some(order_by) => &order_by.elements[..],
+        None => &[],
+    };
+    let mut sort_keys = vec![];
+    for element in elements {
+        match &element.target {
+            models::OrderByTarget::Column { name, path }
+                if path.is_empty() && !table.is_orderable_column(name) =>
+            {
+                return Err(ServerError::BadRequest(format!(
+                    "cannot order by {}, a JSON column",
+                    name
+                )))
+            }
+            models::OrderByTarget::Column { name, path } if path.is_empty() => {
+                context.get_column_value(name, &Map::new()).map_err(|_| {
+                    ServerError::BadRequest(format!("cannot order by unknown column {}", name))
+                })?;
+                sort_keys.push((name, &element.order_direction));
+            }
+            _ => {
+                return Err(ServerError::BadRequest(
+                    "only ordering by the columns of the table is supported".into(),
+                ))
+            }
+        }
+    }
+    let mut ordered = vec![];
+    for row in matched {
+        let mut keys = vec![];
+        for (name, _) in &sort_keys {
+            keys.push(context.get_column_value(name, row)?);
+        }
+        ordered.push((keys, row));
+    }
+    ordered.sort_by(|(a, _), (b, _)| {
+        sort_keys
+            .iter()
+            .zip(a.iter().zip(b.iter()))
+            .map(|((_, direction), (a, b))| match direction {
+                models::OrderDirection::Asc => compare_values(a, b),
+                models::OrderDirection::Desc => compare_values(a, b).reverse(),
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut response_rows = vec![];
+    for (_, row) in ordered
+        .into_iter()
+        .skip(query.offset.unwrap_or(0) as usize)
+        .take(query.limit.map_or(usize::MAX, |limit| limit as usize))
+    {
+        let mut response_row = Map::new();
+        for (alias, field) in fields {
+            match field {
+                models::Field::Column { column, .. } => {
+                    let value = context.get_column_value(column, row)?;
+                    response_row.insert(alias.clone(), json!({ "value": value }));
+                }
+                models::Field::Relationship { .. } => {
+                    return Err(ServerError::BadRequest(
+                        "relationships are not supported".into(),
+                    ))
+                }
+            }
+        }
+        response_rows.push(Value::Object(response_row));
+    }
+
+    Ok(json!([{ "rows": response_rows }]))
+}
+
+// a table of a snapshot, that the predicates of a query are evaluated against
+struct SnapshotTable<'a> {
+    snapshot: &'a Snapshot,
+    table: &'a SupportedTable,
+}
+
+impl SnapshotTable<'_> {
+    // gets the value of a column of a row. database_name is the database the snapshot was taken
+    // of, for the tables that do not have a column of that name.
+    fn get_column_value(
+        &self,
+        column: &str,
+        row: &Map<String, Value>,
+    ) -> Result<Value, ServerError> {
+        if self.table.get_columns().iter().any(|c| c.name == column) {
+            Ok(row.get(column).cloned().unwrap_or(Value::Null))
+        } else if column == DATABASE_NAME_COLUMN {
+            Ok(Value::String(self.snapshot.database.clone()))
+        } else {
+            Err(ServerError::BadRequest(format!(
+                "unknown column {}",
+                column
+            )))
+        }
+    }
+
+    // evaluates a predicate like Postgres does: a comparison with null is unknown (None),
+    // and only the rows the predicate is true for match
+    fn evaluate(
+        &self,
+        expression: &Expression,
+        row: &Map<String, Value>,
+    ) -> Result<Option<bool>, ServerError> {
+        match expression {
+            Expression::And { expressions } => {
+                let mut result = Some(true);
+                for e in expressions {
+                    match self.evaluate(e, row)? {
+                        Some(false) => return Ok(Some(false)),
+                        None => result = None,
+                        Some(true) => {}
+                    }
+                }
+                Ok(result)
+            }
+            Expression::Or { expressions } => {
+                let mut result = Some(false);
+                for e in expressions {
+                    match self.evaluate(e, row)? {
+                        Some(true) => return Ok(Some(true)),
+                        None => result = None,
+                        Some(false) => {}
+                    }
+                }
+                Ok(result)
+            }
+            Expression::Not { expression } => Ok(self.evaluate(expression, row)?.map(|r| !r)),
+            Expression::BinaryComparisonOperator {
+                column,
+                operator,
+                value,
+            } => {
+                let left = match &**column {
+                    models::ComparisonTarget::RootTableColumn { name } => {
+                        self.get_column_value(name, row)?
+                    }
+                    models::ComparisonTarget::Column { name, path } if path.is_empty() => {
+                        self.get_column_value(name, row)?
+                    }
+                    models::ComparisonTarget::Column { .. } => {
+                        return Err(ServerError::BadRequest(
+                            "comparison against other tables is not supported".into(),
+                        ))
+                    }
+                };
+                let right = match &**value {
+                    models::ComparisonValue::Scalar { value } => value,
+                    _ => {
+                        return Err(ServerError::BadRequest(
+                            "only comparisons with scalar values are supported".into(),
+                        ))
+                    }
+                };
+                if left.is_null() || right.is_null() {
+                    return Ok(None);
+                }
+                let result = match &**operator {
+                    models::BinaryComparisonOperator::Equal => compare_values(&left, right).is_eq(),
+                    models::BinaryComparisonOperator::Other { name } => match name.as_str() {
+                        "like" => is_like(&left, right, false),
+                        "nlike" => !is_like(&left, right, false),
+                        "ilike" => is_like(&left, right, true),
+                        "nilike" => !is_like(&left, right, true),
+                        "gt" => compare_values(&left, right).is_gt(),
+                        "gte" => compare_values(&left, right).is_ge(),
+                        "lt" => compare_values(&left, right).is_lt(),
+                        "lte" => compare_values(&left, right).is_le(),
+                        _ => {
+                            return Err(ServerError::BadRequest(format!(
+                                "unsupported comparison operator {}",
+                                name
+                            )))
+                        }
+                    },
+                };
+                Ok(Some(result))
+            }
+            _ => Err(ServerError::BadRequest(
+                "unsupported predicate expression".into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn get_test_snapshot() -> Snapshot {
+        let tables = json!([
+            { "table_schema": "public", "table_name": "orders", "table_type": "BASE TABLE", "is_partition": false, "is_partitioned": false },
+            { "table_schema": "public", "table_name": "customers", "table_type": "BASE TABLE", "is_partition": false, "is_partitioned": false },
+            { "table_schema": "public", "table_name": "Order_Lines", "table_type": "BASE TABLE", "is_partition": false, "is_partitioned": true },
+            { "table_schema": "public", "table_name": "order_lines_2023", "table_type": "BASE TABLE", "is_partition": true, "is_partitioned": false },
+            { "table_schema": "billing", "table_name": "invoices", "table_type": null, "is_partition": false, "is_partitioned": false },
+        ]);
+        let dependencies = json!([
+            { "depth": 1, "referencing_name": "orders_view" },
+            { "depth": 2, "referencing_name": "orders_summary" },
+        ]);
+        let policies = json!([
+            { "table_schema": "public", "table_name": "orders", "policy_name": "own_orders", "roles": ["clerk"] },
+        ]);
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            created_at: "2023-07-01T00:00:00Z".into(),
+            database: "shop".into(),
+            server_version: "15.3".into(),
+            collections: BTreeMap::from_iter([
+                ("tables".into(), serde_json::from_value(tables).unwrap()),
+                (
+                    "dependencies".into(),
+                    serde_json::from_value(dependencies).unwrap(),
+                ),
+                ("policies".into(), serde_json::from_value(policies).unwrap()),
+            ]),
+        }
+    }
+
+    fn get_request(table: &str, query: Value, arguments: Value) -> QueryRequest {
+        serde_json::from_value(json!({
+            "table": table,
+            "query": query,
+            "arguments": arguments,
+            "table_relationships": {},
+        }))
+        .expect("the request should be valid")
+    }
+
+    fn get_fields(columns: &[&str]) -> Value {
+        columns
+            .iter()
+            .map(|column| {
+                (
+                    column.to_string(),
+                    json!({ "type": "column", "column": column, "arguments": {} }),
+                )
+            })
+            .collect::<Map<String, Value>>()
+            .into()
+    }
+
+    fn get_comparison(column: &str, operator: Value, value: Value) -> Value {
+        json!({
+            "type": "binary_comparison_operator",
+            "column": { "type": "root_table_column", "name": column },
+            "operator": operator,
+            "value": { "type": "scalar", "value": value },
+        })
+    }
+
+    fn get_table_names(response: &Value) -> Vec<&str> {
+        response[0]["rows"]
+            .as_array()
+            .expect("the response should have rows")
+            .iter()
+            .map(|row| row["table_name"]["value"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn rows_are_filtered_ordered_and_paged() {
+        let request = get_request(
+            "tables",
+            json!({
+                "fields": get_fields(&["table_name"]),
+                "where": get_comparison("table_schema", json!({ "type": "equal" }), json!("public")),
+                "order_by": { "elements": [{
+                    "order_direction": "asc",
+                    "target": { "type": "column", "name": "table_name", "path": [] },
+                }] },
+                "limit": 2,
+                "offset": 1,
+            }),
+            json!({}),
+        );
+        let response = query_snapshot(&get_test_snapshot(), &request).unwrap();
+        // strings are ordered by code point, so upper case comes first
+        assert_eq!(
+            get_table_names(&response),
+            vec!["customers", "order_lines_2023"]
+        );
+    }
+
+    #[test]
+    fn comparisons_with_null_do_not_match() {
+        let not_base_table = json!({
+            "type": "not",
+            "expression": get_comparison("table_type", json!({ "type": "equal" }), json!("BASE TABLE")),
+        });
+        let request = get_request(
+            "tables",
+            json!({ "fields": get_fields(&["table_name"]), "where": not_base_table }),
+            json!({}),
+        );
+        let response = query_snapshot(&get_test_snapshot(), &request).unwrap();
+        assert!(get_table_names(&response).is_empty());
+    }
+
+    #[test]
+    fn like_operators_match_patterns() {
+        let operator = json!({ "type": "other", "name": "ilike" });
+        let request = get_request(
+            "tables",
+            json!({
+                "fields": get_fields(&["table_name"]),
+                "where": get_comparison("table_name", operator, json!("order\\_%")),
+            }),
+            json!({}),
+        );
+        let response = query_snapshot(&get_test_snapshot(), &request).unwrap();
+        assert_eq!(
+            get_table_names(&response),
+            vec!["Order_Lines", "order_lines_2023"]
+        );
+    }
+
+    #[test]
+    fn table_arguments_are_applied() {
+        let request = get_request(
+            "tables",
+            json!({ "fields": get_fields(&["table_name", "database_name"]) }),
+            json!({ "exclude_partitions": { "type": "literal", "value": true } }),
+        );
+        let response = query_snapshot(&get_test_snapshot(), &request).unwrap();
+        assert!(!get_table_names(&response).contains(&"order_lines_2023"));
+        assert_eq!(response[0]["rows"][0]["database_name"]["value"], "shop");
+
+        let request = get_request(
+            "dependencies",
+            json!({ "fields": get_fields(&["referencing_name"]) }),
+            json!({}),
+        );
+        let response = query_snapshot(&get_test_snapshot(), &request).unwrap();
+        assert_eq!(response[0]["rows"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn unknown_columns_and_relationships_are_rejected() {
+        let snapshot = get_test_snapshot();
+        let request = get_request(
+            "tables",
+            json!({ "fields": get_fields(&["no_such_column"]) }),
+            json!({}),
+        );
+        assert!(query_snapshot(&snapshot, &request).is_err());
+        let request = get_request(
+            "tables",
+            json!({ "fields": { "owner": {
+                "type": "relationship",
+                "relationship": "TableToOwnerRole",
+                "arguments": {},
+                "query": { "fields": get_fields(&["role_name"]) },
+            } } }),
+            json!({}),
+        );
+        assert!(query_snapshot(&snapshot, &request).is_err());
+    }
+
+    #[test]
+    fn json_columns_cannot_be_ordered_by() {
+        let order_by = |column: &str| {
+            json!({ "elements": [{
+                "order_direction": "asc",
+                "target": { "type": "column", "name": column, "path": [] },
+            }] })
+        };
+        let request = get_request(
+            "policies",
+            json!({ "fields": get_fields(&["policy_name"]), "order_by": order_by("roles") }),
+            json!({}),
+        );
+        assert!(query_snapshot(&get_test_snapshot(), &request).is_err());
+        let request = get_request(
+            "policies",
+            json!({ "fields": get_fields(&["roles"]), "order_by": order_by("policy_name") }),
+            json!({}),
+        );
+        let response = query_snapshot(&get_test_snapshot(), &request).unwrap();
+        assert_eq!(response[0]["rows"][0]["roles"]["value"], json!(["clerk"]));
+    }
+
+    #[test]
+    fn snapshots_are_read_from_the_snapshot_directory_only() {
+        let directory =
+            std::env::temp_dir().join(format!("cc_postgres_snapshots_{}", std::process::id()));
+        let snapshot_dir = directory.join("snapshots");
+        fs::create_dir_all(&snapshot_dir).unwrap();
+        fs::write(snapshot_dir.join("production.json"), "{}").unwrap();
+        fs::write(directory.join("secret.json"), "{}").unwrap();
+        let snapshot_dir_name = snapshot_dir.to_str();
+
+        assert!(get_snapshot_path(snapshot_dir_name, "production").is_ok());
+        assert!(get_snapshot_path(None, "production").is_err());
+        for name in ["", "missing", "../secret", "/etc/passwd", ".hidden", "a\\b"] {
+            assert!(
+                get_snapshot_path(snapshot_dir_name, name).is_err(),
+                "{}",
+                name
+            );
+        }
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(
+                directory.join("secret.json"),
+                snapshot_dir.join("link.json"),
+            )
+            .unwrap();
+            assert!(get_snapshot_path(snapshot_dir_name, "link").is_err());
+        }
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn snapshots_of_other_versions_are_rejected() {
+        assert!(parse_snapshot("old", r#"{"version": 0}"#).is_err());
+        assert!(parse_snapshot("other", r#"{"tables": []}"#).is_err());
+        let snapshot = serde_json::to_string(&get_test_snapshot()).unwrap();
+        assert_eq!(parse_snapshot("shop", &snapshot).unwrap().database, "shop");
+    }
+}
@@ -1,39 +1,111 @@
+mod catalog;
 mod fkey;
 mod predicate_builder;
 mod utils;
 
 use ndc_client::models::{self, Expression};
 use sqlparser::ast::{
-    Expr, ObjectName, Query, Select, SelectItem, SetExpr, Statement, TableAlias, TableFactor,
-    TableWithJoins, Value,
+    DataType, Expr, ObjectName, OrderByExpr, Query, Select, SelectItem, SetExpr, Statement,
+    TableAlias, TableFactor, TableWithJoins, Value,
 };
 use std::collections::HashMap;
 use std::str::FromStr;
 
+use crate::arguments::{get_bool_argument, TableArguments};
+use crate::configuration::CatalogBackend;
 use crate::error::ServerError;
-use crate::tables::SupportedTable;
+use crate::tables::{SupportedTable, DATABASE_NAME_COLUMN};
 
+use catalog::get_catalog_subquery;
 use fkey::get_fkey_query;
 use predicate_builder::get_predicate_expression;
 use utils::{get_sql_function_expression, get_sql_query, get_sql_quoted_identifier};
 
-pub fn build_sql_query(request: &models::QueryRequest) -> Result<Statement, ServerError> {
+pub fn build_sql_query(
+    request: &models::QueryRequest,
+    backend: CatalogBackend,
+) -> Result<Statement, ServerError> {
     let table = SupportedTable::from_str(&request.table);
 
     match table {
-        Ok(t) => Ok(Statement::Query(get_node_subquery(&request.query, &t)?)),
+        Ok(t) => {
+            let query = get_query_with_arguments(request, &t);
+            let table_arguments = TableArguments::from_request(request)?;
+            Ok(Statement::Query(get_node_subquery(
+                &query,
+                &t,
+                &table_arguments,
+                backend,
+            )?))
+        }
         Err(_) => Err(ServerError::BadRequest("unknown table".into())),
     }
 }
 
+// gets the query of the request, with the table arguments applied as additional predicates
+pub fn get_query_with_arguments(
+    request: &models::QueryRequest,
+    table: &SupportedTable,
+) -> models::Query {
+    match get_arguments_predicate(request, table) {
+        Some(arguments_predicate) => models::Query {
+            predicate: Some(match &request.query.predicate {
+                Some(p) => Expression::And {
+                    expressions: vec![arguments_predicate, p.clone()],
+                },
+                None => arguments_predicate,
+            }),
+            ..request.query.clone()
+        },
+        None => request.query.clone(),
+    }
+}
+
+// builds the predicate for the arguments of the table that filter its rows
+fn get_arguments_predicate(
+    request: &models::QueryRequest,
+    table: &SupportedTable,
+) -> Option<Expression> {
+    let variables = request.variables.clone().unwrap_or_default();
+    match table {
+        // leaf partitions are partitions that are not partitioned themselves
+        SupportedTable::Tables
+            if get_bool_argument(&request.arguments, &variables, "exclude_partitions") =>
+        {
+            Some(Expression::Not {
+                expression: Box::new(Expression::And {
+                    expressions: vec![
+                        get_column_equal_expression("is_partition", true.into()),
+                        get_column_equal_expression("is_partitioned", false.into()),
+                    ],
+                }),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn get_column_equal_expression(column: &str, value: serde_json::Value) -> Expression {
+    Expression::BinaryComparisonOperator {
+        column: Box::new(models::ComparisonTarget::RootTableColumn {
+            name: column.into(),
+        }),
+        operator: Box::new(models::BinaryComparisonOperator::Equal),
+        value: Box::new(models::ComparisonValue::Scalar { value }),
+    }
+}
+
 pub fn get_node_subquery(
     query: &ndc_client::models::Query,
     table: &SupportedTable,
+    table_arguments: &TableArguments,
+    backend: CatalogBackend,
 ) -> Result<Box<Query>, ServerError> {
     // query wrapper projection
     let wrapper_projection = match &query.fields {
         Some(f) => {
-            let rows_subquery = get_rows_json_subquery(f.clone(), query, table);
+            let rows_subquery =
+                get_rows_json_subquery(f.clone(), query, table, table_arguments, backend);
             match rows_subquery {
                 Ok(q) => vec![SelectItem::ExprWithAlias {
                     expr: Expr::Subquery(q),
@@ -113,10 +185,12 @@ fn get_rows_json_subquery(
     fields: HashMap<String, models::Field>,
     query: &models::Query,
     table: &SupportedTable,
+    table_arguments: &TableArguments,
+    backend: CatalogBackend,
 ) -> Result<Box<Query>, ServerError> {
     let row_subquery = match table {
-        SupportedTable::Columns | SupportedTable::Tables => get_rows_query(query, table),
-        SupportedTable::ForeignKeys {} => get_fkey_query(query, table),
+        SupportedTable::ForeignKeys => get_fkey_query(query, table),
+        _ => get_rows_query(query, table, table_arguments, backend),
     };
 
     let rows_json_projection = vec![SelectItem::ExprWithAlias {
@@ -146,10 +220,7 @@ fn get_rows_json_subquery(
         joins: vec![],
         relation: TableFactor::Derived {
             lateral: false,
-            subquery: match row_subquery {
-                Ok(q) => q,
-                Err(_) => todo!("todo"),
-            },
+            subquery: row_subquery?,
             alias: Some(TableAlias {
                 name: get_sql_quoted_identifier("_rows"),
                 columns: vec![],
@@ -170,34 +241,41 @@ fn get_rows_json_subquery(
 pub fn get_rows_query(
     query: &ndc_client::models::Query,
     table: &SupportedTable,
+    table_arguments: &TableArguments,
+    backend: CatalogBackend,
 ) -> Result<Box<Query>, ServerError> {
     /*Build Predicate*/
-    // start with a custom predicate to ignore tables from information_schema and pg_catalog
-    let mut predicate = Expression::And {
-        expressions: vec![
-            (Expression::BinaryComparisonOperator {
-                column: Box::new(models::ComparisonTarget::RootTableColumn {
-                    name: "table_schema".into(),
+    // start with a custom predicate to ignore rows from information_schema and pg_catalog
+    let mut predicate = match table.get_schema_column() {
+        Some(schema_column) => Expression::And {
+            expressions: vec![
+                (Expression::BinaryComparisonOperator {
+                    column: Box::new(models::ComparisonTarget::RootTableColumn {
+                        name: schema_column.into(),
+                    }),
+                    operator: Box::new(models::BinaryComparisonOperator::Other {
+                        name: "nlike".into(),
+                    }),
+                    value: Box::new(models::ComparisonValue::Scalar {
+                        value: "pg_%".into(),
+                    }),
                 }),
-                operator: Box::new(models::BinaryComparisonOperator::Other {
-                    name: "nlike".into(),
+                (Expression::BinaryComparisonOperator {
+                    column: Box::new(models::ComparisonTarget::RootTableColumn {
+                        name: schema_column.into(),
+                    }),
+                    operator: Box::new(models::BinaryComparisonOperator::Other {
+                        name: "nlike".into(),
+                    }),
+                    value: Box::new(models::ComparisonValue::Scalar {
+                        value: "information_schema".into(),
+                    }),
                 }),
-                value: Box::new(models::ComparisonValue::Scalar {
-                    value: "pg_%".into(),
-                }),
-            }),
-            (Expression::BinaryComparisonOperator {
-                column: Box::new(models::ComparisonTarget::RootTableColumn {
-                    name: "table_schema".into(),
-                }),
-                operator: Box::new(models::BinaryComparisonOperator::Other {
-                    name: "nlike".into(),
-                }),
-                value: Box::new(models::ComparisonValue::Scalar {
-                    value: "information_schema".into(),
-                }),
-            }),
-        ],
+            ],
+        },
+        None => Expression::And {
+            expressions: vec![],
+        },
     };
     // append the actual predicate coming from the query
     predicate = match &query.predicate {
@@ -207,23 +285,33 @@ pub fn get_rows_query(
         None => predicate,
     };
     // get the predicate expression required by the sqlx client
-    let filter_predicate = get_predicate_expression(&predicate, "_origin");
+    let filter_predicate = get_predicate_expression(&predicate, table, "_origin")?;
 
-    // from clause
+    // from clause; either the underlying catalog table or the catalog subquery of the table
     let rows_from = vec![TableWithJoins {
         joins: vec![],
-        relation: TableFactor::Table {
-            // note: assuming the table name is not aliased in any way, will need to change this
-            name: ObjectName(vec![
-                get_sql_quoted_identifier(table.get_schema_name()),
-                get_sql_quoted_identifier(table.get_table_name()),
-            ]),
-            alias: Some(TableAlias {
-                name: get_sql_quoted_identifier("_origin"),
-                columns: vec![],
-            }),
-            args: None,
-            with_hints: vec![],
+        relation: match get_catalog_subquery(table, table_arguments, &predicate, backend)? {
+            Some(subquery) => TableFactor::Derived {
+                lateral: false,
+                subquery,
+                alias: Some(TableAlias {
+                    name: get_sql_quoted_identifier("_origin"),
+                    columns: vec![],
+                }),
+            },
+            None => TableFactor::Table {
+                // note: assuming the table name is not aliased in any way, will need to change this
+                name: ObjectName(vec![
+                    get_sql_quoted_identifier(table.get_schema_name()),
+                    get_sql_quoted_identifier(table.get_table_name()),
+                ]),
+                alias: Some(TableAlias {
+                    name: get_sql_quoted_identifier("_origin"),
+                    columns: vec![],
+                }),
+                args: None,
+                with_hints: vec![],
+            },
         },
     }];
 
@@ -238,40 +326,218 @@ pub fn get_rows_query(
     } else {
         fields
             .iter()
-            .map(|(alias, field)| SelectItem::ExprWithAlias {
-                expr: get_sql_function_expression(
-                    "json_build_object",
-                    vec![
-                        Expr::Value(Value::SingleQuotedString("value".to_string())),
-                        match field {
-                            models::Field::Column { column, .. } => {
-                                let table_info = table.get_table_info();
-                                let column_info = table_info
-                                    .columns
-                                    .iter()
-                                    .find(|c| c.name == column.clone())
-                                    .expect("column should be in table");
-                                Expr::CompoundIdentifier(vec![
-                                    get_sql_quoted_identifier("_origin"),
-                                    get_sql_quoted_identifier(&column_info.name),
-                                ])
-                            }
-                            models::Field::Relationship { .. } => todo!(),
-                        },
-                    ],
-                    None,
-                ),
-                alias: get_sql_quoted_identifier(alias),
+            .map(|(alias, field)| {
+                Ok(SelectItem::ExprWithAlias {
+                    expr: get_sql_function_expression(
+                        "json_build_object",
+                        vec![
+                            Expr::Value(Value::SingleQuotedString("value".to_string())),
+                            match field {
+                                models::Field::Column { column, .. } => get_column_expression(
+                                    table, "_origin", column,
+                                )
+                                .ok_or_else(|| {
+                                    ServerError::BadRequest(format!("unknown column {}", column))
+                                })?,
+                                models::Field::Relationship { .. } => {
+                                    return Err(ServerError::BadRequest(
+                                        "relationships are not supported".into(),
+                                    ))
+                                }
+                            },
+                        ],
+                        None,
+                    ),
+                    alias: get_sql_quoted_identifier(alias),
+                })
             })
-            .collect()
+            .collect::<Result<Vec<_>, ServerError>>()?
     };
 
-    Ok(get_sql_query(
+    let mut rows_query = get_sql_query(
         rows_projection,
         rows_from,
         Some(filter_predicate),
         None,
         query.limit,
         query.offset,
-    ))
+    );
+    // the rows are aggregated with json_agg in the order of this subquery
+    rows_query.order_by = get_order_by_expressions(query, table, table_arguments)?;
+
+    Ok(rows_query)
+}
+
+// gets the expression for a column of the table, whose rows have the alias. database_name is
+// the database the row is read from, for the tables that do not have a column of that name.
+fn get_column_expression(table: &SupportedTable, alias: &str, column: &str) -> Option<Expr> {
+    let table_info = table.get_table_info();
+    match table_info.columns.iter().find(|c| c.name == column) {
+        Some(column_info) => Some(Expr::CompoundIdentifier(vec![
+            get_sql_quoted_identifier(alias),
+            get_sql_quoted_identifier(&column_info.name),
+        ])),
+        None if column == DATABASE_NAME_COLUMN => Some(get_sql_function_expression(
+            "current_database",
+            vec![],
+            None,
+        )),
+        None => None,
+    }
+}
+
+// gets the ORDER BY of the rows. When the rows of all databases are merged, strings are
+// ordered by their bytes with the C collation, so that every database returns the same rows
+// first that the merge keeps.
+fn get_order_by_expressions(
+    query: &models::Query,
+    table: &SupportedTable,
+    table_arguments: &TableArguments,
+) -> Result<Vec<OrderByExpr>, ServerError> {
+    let elements = match &query.order_by {
+        Some(order_by) => &order_by.elements[..],
+        None => &[],
+    };
+    elements
+        .iter()
+        .map(|element| match &element.target {
+            models::OrderByTarget::Column { name, path }
+                if path.is_empty() && !table.is_orderable_column(name) =>
+            {
+                Err(ServerError::BadRequest(format!(
+                    "cannot order by {}, a JSON column",
+                    name
+                )))
+            }
+            models::OrderByTarget::Column { name, path } if path.is_empty() => {
+                match get_column_expression(table, "_origin", name) {
+                    Some(expr) => Ok(OrderByExpr {
+                        expr: match table_arguments.all_databases && is_string_column(table, name) {
+                            true => Expr::Collate {
+                                expr: Box::new(Expr::Cast {
+                                    expr: Box::new(expr),
+                                    data_type: DataType::Text,
+                                }),
+                                collation: ObjectName(vec![get_sql_quoted_identifier("C")]),
+                            },
+                            false => expr,
+                        },
+                        asc: Some(matches!(
+                            element.order_direction,
+                            models::OrderDirection::Asc
+                        )),
+                        nulls_first: None,
+                    }),
+                    None => Err(ServerError::BadRequest(format!(
+                        "cannot order by unknown column {}",
+                        name
+                    ))),
+                }
+            }
+            _ => Err(ServerError::BadRequest(
+                "only ordering by the columns of the table is supported".into(),
+            )),
+        })
+        .collect()
+}
+
+fn is_string_column(table: &SupportedTable, column: &str) -> bool {
+    match table
+        .get_table_info()
+        .columns
+        .iter()
+        .find(|c| c.name == column)
+    {
+        Some(column_info) => column_info.r#type == "String",
+        None => column == DATABASE_NAME_COLUMN,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::SUPPORTED_TABLES;
+    use serde_json::json;
+    use sqlparser::dialect::PostgreSqlDialect;
+    use sqlparser::parser::Parser;
+
+    // a request for every column of the table
+    fn get_request(table: &str, arguments: serde_json::Value) -> models::QueryRequest {
+        let fields: serde_json::Map<String, serde_json::Value> = SupportedTable::from_str(table)
+            .expect("the table should be supported")
+            .get_columns()
+            .iter()
+            .map(|c| {
+                (
+                    c.name.clone(),
+                    json!({ "type": "column", "column": c.name, "arguments": {} }),
+                )
+            })
+            .collect();
+        serde_json::from_value(json!({
+            "table": table,
+            "query": { "fields": fields, "limit": 10 },
+            "arguments": arguments,
+            "table_relationships": {},
+        }))
+        .expect("the request should be valid")
+    }
+
+    fn get_ordered_request(table: &str, column: &str, arguments: serde_json::Value) -> String {
+        let mut request = get_request(table, arguments);
+        request.query.order_by = serde_json::from_value(json!({ "elements": [{
+            "order_direction": "asc",
+            "target": { "type": "column", "name": column, "path": [] },
+        }] }))
+        .unwrap();
+        match build_sql_query(&request, CatalogBackend::InformationSchema) {
+            Ok(statement) => statement.to_string(),
+            Err(ServerError::BadRequest(message)) => message,
+            Err(_) => panic!("the query should be built or rejected"),
+        }
+    }
+
+    #[test]
+    fn fan_out_orders_text_columns_by_bytes() {
+        let all_databases = json!({ "all_databases": { "type": "literal", "value": true } });
+        let sql = get_ordered_request("column_stats", "table_name", all_databases.clone());
+        assert!(sql.contains(r#"ORDER BY CAST("_origin"."table_name" AS TEXT) COLLATE "C" ASC"#));
+        let sql = get_ordered_request("column_stats", "null_frac", all_databases);
+        assert!(sql.contains(r#"ORDER BY "_origin"."null_frac" ASC"#));
+        let sql = get_ordered_request("column_stats", "table_name", json!({}));
+        assert!(sql.contains(r#"ORDER BY "_origin"."table_name" ASC"#) && !sql.contains("COLLATE"));
+    }
+
+    #[test]
+    fn json_columns_cannot_be_ordered_by() {
+        let all_databases = json!({ "all_databases": { "type": "literal", "value": true } });
+        for (table, column) in [
+            ("column_stats", "most_common_vals"),
+            ("user_mappings", "option_names"),
+            ("policies", "roles"),
+        ] {
+            for arguments in [json!({}), all_databases.clone()] {
+                assert_eq!(
+                    get_ordered_request(table, column, arguments),
+                    format!("cannot order by {}, a JSON column", column)
+                );
+            }
+        }
+    }
+
+    // the catalog queries are hand-written, so a syntax error in one of them only shows up when
+    // its table is queried
+    #[test]
+    fn every_table_builds_a_query_that_parses() {
+        for table in SUPPORTED_TABLES {
+            for backend in [CatalogBackend::InformationSchema, CatalogBackend::PgCatalog] {
+                let statement = build_sql_query(&get_request(table, json!({})), backend)
+                    .unwrap_or_else(|_| panic!("could not build the {} query", table));
+                let sql = statement.to_string();
+                if let Err(err) = Parser::parse_sql(&PostgreSqlDialect {}, &sql) {
+                    panic!("the {} query does not parse: {}\n{}", table, err, sql);
+                }
+            }
+        }
+    }
 }
@@ -6,12 +6,13 @@ use sqlparser::ast::{
 use std::collections::HashMap;
 
 use crate::error::ServerError;
-use crate::tables::SupportedTable;
+use crate::tables::{SupportedTable, DATABASE_NAME_COLUMN};
 
 use crate::sql::predicate_builder::get_predicate_expression;
 use crate::sql::utils::{
     get_equivalent_table_column, get_sql_and_expression, get_sql_eq_expression,
-    get_sql_function_expression, get_sql_like_expr, get_sql_query, get_sql_quoted_identifier,
+    get_sql_function_expression, get_sql_like_expr, get_sql_ordered_function_expression,
+    get_sql_query, get_sql_quoted_identifier,
 };
 
 pub fn get_fkey_query(
@@ -26,7 +27,7 @@ pub fn get_fkey_query(
     // min(q.table_to) AS table_to,
     // min(q.confupdtype) AS on_update,
     // min(q.confdeltype) AS on_delete,
-    // json_object_agg(ac.attname, afc.attname) AS column_mapping
+    // json_agg(json_build_object('from', ac.attname, 'to', afc.attname) ORDER BY q.column_position) AS column_mapping
 
     let binding = HashMap::new();
     let fields = match &query.fields {
@@ -38,66 +39,104 @@ pub fn get_fkey_query(
     } else {
         fields
             .iter()
-            .map(|(alias, field)| SelectItem::ExprWithAlias {
-                expr: get_sql_function_expression(
-                    "json_build_object",
-                    vec![
-                        Expr::Value(Value::SingleQuotedString("value".to_string())),
-                        match field {
-                            models::Field::Column { column, .. } => {
-                                let table_info = table.get_table_info();
-                                let column_info = table_info
-                                    .columns
-                                    .iter()
-                                    .find(|c| c.name == column.clone())
-                                    .expect("column should be in table");
+            .map(|(alias, field)| {
+                Ok(SelectItem::ExprWithAlias {
+                    expr: get_sql_function_expression(
+                        "json_build_object",
+                        vec![
+                            Expr::Value(Value::SingleQuotedString("value".to_string())),
+                            match field {
+                                models::Field::Column { column, .. }
+                                    if column == DATABASE_NAME_COLUMN =>
+                                {
+                                    get_sql_function_expression("current_database", vec![], None)
+                                }
+                                models::Field::Column { column, .. } => {
+                                    let table_info = table.get_table_info();
+                                    let column_info = table_info
+                                        .columns
+                                        .iter()
+                                        .find(|c| c.name == column.clone())
+                                        .ok_or_else(|| {
+                                            ServerError::BadRequest(format!(
+                                                "unknown column {}",
+                                                column
+                                            ))
+                                        })?;
 
-                                match &column_info.name[..] {
-                                    "column_mapping" => get_sql_function_expression(
-                                        "json_object_agg",
-                                        vec![
-                                            Expr::CompoundIdentifier(vec![
-                                                get_sql_quoted_identifier("ac"),
-                                                get_sql_quoted_identifier("attname"),
-                                            ]),
-                                            Expr::CompoundIdentifier(vec![
-                                                get_sql_quoted_identifier("afc"),
-                                                get_sql_quoted_identifier("attname"),
-                                            ]),
-                                        ],
-                                        None,
-                                    ),
-                                    "schema_to" | "table_to" | "on_update" | "on_delete" => {
-                                        get_sql_function_expression(
-                                            "min",
+                                    match &column_info.name[..] {
+                                        // an ordered array of {from, to} pairs, so that the column order of
+                                        // multi-column keys is preserved
+                                        "column_mapping" => get_sql_ordered_function_expression(
+                                            "json_agg",
+                                            vec![get_sql_function_expression(
+                                                "json_build_object",
+                                                vec![
+                                                    Expr::Value(Value::SingleQuotedString(
+                                                        "from".to_string(),
+                                                    )),
+                                                    Expr::CompoundIdentifier(vec![
+                                                        get_sql_quoted_identifier("ac"),
+                                                        get_sql_quoted_identifier("attname"),
+                                                    ]),
+                                                    Expr::Value(Value::SingleQuotedString(
+                                                        "to".to_string(),
+                                                    )),
+                                                    Expr::CompoundIdentifier(vec![
+                                                        get_sql_quoted_identifier("afc"),
+                                                        get_sql_quoted_identifier("attname"),
+                                                    ]),
+                                                ],
+                                                None,
+                                            )],
                                             vec![Expr::CompoundIdentifier(vec![
+                                                get_sql_quoted_identifier("q"),
+                                                get_sql_quoted_identifier("column_position"),
+                                            ])],
+                                        ),
+                                        "schema_to" | "table_to" | "on_update" | "on_delete" => {
+                                            get_sql_function_expression(
+                                                "min",
+                                                vec![Expr::CompoundIdentifier(vec![
+                                                    get_sql_quoted_identifier("q"),
+                                                    get_sql_quoted_identifier(
+                                                        get_equivalent_table_column(
+                                                            &column_info.name,
+                                                        ),
+                                                    ),
+                                                ])],
+                                                None,
+                                            )
+                                        }
+                                        "schema_from" | "table_from" | "fkey_name" => {
+                                            Expr::CompoundIdentifier(vec![
                                                 get_sql_quoted_identifier("q"),
                                                 get_sql_quoted_identifier(
                                                     get_equivalent_table_column(&column_info.name),
                                                 ),
-                                            ])],
-                                            None,
-                                        )
-                                    }
-                                    "schema_from" | "table_from" | "fkey_name" => {
-                                        Expr::CompoundIdentifier(vec![
-                                            get_sql_quoted_identifier("q"),
-                                            get_sql_quoted_identifier(get_equivalent_table_column(
-                                                &column_info.name,
-                                            )),
-                                        ])
+                                            ])
+                                        }
+                                        _ => {
+                                            return Err(ServerError::BadRequest(format!(
+                                                "unknown column {}",
+                                                column
+                                            )))
+                                        }
                                     }
-                                    _ => todo!(),
                                 }
-                            }
-                            models::Field::Relationship { .. } => todo!(),
-                        },
-                    ],
-                    None,
-                ),
-                alias: get_sql_quoted_identifier(alias),
+                                models::Field::Relationship { .. } => {
+                                    return Err(ServerError::BadRequest(
+                                        "relationships are not supported".into(),
+                                    ))
+                                }
+                            },
+                        ],
+                        None,
+                    ),
+                    alias: get_sql_quoted_identifier(alias),
+                })
             })
-            .collect()
+            .collect::<Result<Vec<_>, ServerError>>()?
     };
 
     // Builds from clause. This is the SQL equivalent from clause with the joins:
@@ -167,7 +206,11 @@ pub fn get_fkey_query(
 
     // append the predicate coming from the query
     let predicate: Option<Expr> = match &query.predicate {
-        Some(p) => Some(get_predicate_expression(p, "q")),
+        Some(p) => Some(get_predicate_expression(
+            p,
+            &SupportedTable::ForeignKeys,
+            "q",
+        )?),
         None => None,
     };
 
@@ -202,7 +245,8 @@ fn get_fkey_subquery() -> Box<Query> {
     // 		r.confupdtype,
     // 		r.confdeltype,
     // 		unnest(r.conkey) AS column_id,
-    // 		unnest(r.confkey) AS ref_column_id
+    // 		unnest(r.confkey) AS ref_column_id,
+    // 		generate_subscripts(r.conkey, 1) AS column_position
 
     let rows_projection = vec![
         SelectItem::ExprWithAlias {
@@ -284,6 +328,20 @@ fn get_fkey_subquery() -> Box<Query> {
             ),
             alias: get_sql_quoted_identifier("ref_column_id"),
         },
+        SelectItem::ExprWithAlias {
+            expr: get_sql_function_expression(
+                "generate_subscripts",
+                vec![
+                    Expr::CompoundIdentifier(vec![
+                        get_sql_quoted_identifier("r"),
+                        get_sql_quoted_identifier("conkey"),
+                    ]),
+                    Expr::Value(Value::Number("1".to_string(), false)),
+                ],
+                None,
+            ),
+            alias: get_sql_quoted_identifier("column_position"),
+        },
     ];
 
     // Builds from statement, the equivalent sql query looks like this:
@@ -2,16 +2,26 @@ use ndc_client::models::{self};
 use sqlparser::ast::Expr;
 use sqlparser::ast::{BinaryOperator, UnaryOperator, Value};
 
+use crate::error::ServerError;
+use crate::sql::get_column_expression;
 use crate::sql::utils::{
-    get_sql_and_expression, get_sql_like_expr, get_sql_or_expr, get_sql_quoted_identifier,
+    get_sql_and_expression, get_sql_ilike_expr, get_sql_like_expr, get_sql_or_expr,
 };
+use crate::tables::SupportedTable;
 
-// builds a predicate expression as expected by the sqlx client
-pub fn get_predicate_expression(expr: &models::Expression, alias: &str) -> Expr {
+// builds a predicate expression as expected by the sqlx client, for the rows of the table that
+// have the alias
+pub fn get_predicate_expression(
+    expr: &models::Expression,
+    table: &SupportedTable,
+    alias: &str,
+) -> Result<Expr, ServerError> {
     match expr {
-        models::Expression::And { expressions } => expressions
+        models::Expression::And { expressions } => Ok(expressions
             .iter()
-            .map(|e| get_predicate_expression(e, alias))
+            .map(|e| get_predicate_expression(e, table, alias))
+            .collect::<Result<Vec<_>, ServerError>>()?
+            .into_iter()
             .reduce(get_sql_and_expression)
             .map(|e| match e {
                 Expr::BinaryOp {
@@ -20,10 +30,12 @@ pub fn get_predicate_expression(expr: &models::Expression, alias: &str) -> Expr
                 } => Expr::Nested(Box::new(e)),
                 _ => e,
             })
-            .unwrap_or_else(|| Expr::Value(Value::Boolean(true))),
-        models::Expression::Or { expressions } => expressions
+            .unwrap_or_else(|| Expr::Value(Value::Boolean(true)))),
+        models::Expression::Or { expressions } => Ok(expressions
             .iter()
-            .map(|e| get_predicate_expression(e, alias))
+            .map(|e| get_predicate_expression(e, table, alias))
+            .collect::<Result<Vec<_>, ServerError>>()?
+            .into_iter()
             .reduce(get_sql_or_expr)
             .map(|e| match e {
                 Expr::BinaryOp {
@@ -32,12 +44,14 @@ pub fn get_predicate_expression(expr: &models::Expression, alias: &str) -> Expr
                 } => Expr::Nested(Box::new(e)),
                 _ => e,
             })
-            .unwrap_or_else(|| Expr::Value(Value::Boolean(false))),
-        models::Expression::Not { expression } => Expr::UnaryOp {
+            .unwrap_or_else(|| Expr::Value(Value::Boolean(false)))),
+        models::Expression::Not { expression } => Ok(Expr::UnaryOp {
             op: UnaryOperator::Not,
-            expr: Box::new(get_predicate_expression(expression, alias)),
-        },
-        models::Expression::UnaryComparisonOperator { .. } => todo!(),
+            expr: Box::new(get_predicate_expression(expression, table, alias)?),
+        }),
+        models::Expression::UnaryComparisonOperator { .. } => Err(ServerError::BadRequest(
+            "unary comparison operators are not supported".into(),
+        )),
         models::Expression::BinaryComparisonOperator {
             column,
             operator,
@@ -48,37 +62,39 @@ pub fn get_predicate_expression(expr: &models::Expression, alias: &str) -> Expr
             let column = &**column;
             let value = &**value;
 
-            let left = match column {
-                models::ComparisonTarget::RootTableColumn { name } => {
-                    Expr::CompoundIdentifier(vec![
-                        get_sql_quoted_identifier(alias),
-                        get_sql_quoted_identifier(name),
-                    ])
-                }
+            let name = match column {
+                models::ComparisonTarget::RootTableColumn { name } => name,
                 models::ComparisonTarget::Column { name, path } => {
                     if !path.is_empty() {
-                        todo!("comparison against other tables not supported")
+                        return Err(ServerError::BadRequest(
+                            "comparison against other tables is not supported".into(),
+                        ));
                     }
-                    Expr::CompoundIdentifier(vec![
-                        get_sql_quoted_identifier(alias),
-                        get_sql_quoted_identifier(name),
-                    ])
+                    name
                 }
             };
+            let left = get_column_expression(table, alias, name)
+                .ok_or_else(|| ServerError::BadRequest(format!("unknown column {}", name)))?;
 
             let right = match value {
                 models::ComparisonValue::Column { .. } => {
-                    todo!("Column comparison not supported")
+                    return Err(ServerError::BadRequest(
+                        "column comparison is not supported".into(),
+                    ))
                 }
                 models::ComparisonValue::Scalar { value } => match value {
-                    serde_json::Value::Number(n) => Expr::Value(Value::Number(n.to_string(), true)),
+                    serde_json::Value::Number(n) => {
+                        Expr::Value(Value::Number(n.to_string(), false))
+                    }
                     serde_json::Value::String(s) => {
                         Expr::Value(Value::SingleQuotedString(s.to_string()))
                     }
                     _ => Expr::Value(Value::Placeholder(value.to_string())),
                 },
                 models::ComparisonValue::Variable { .. } => {
-                    todo!("Not sure what variable comparison is")
+                    return Err(ServerError::BadRequest(
+                        "variable comparison is not supported".into(),
+                    ))
                 }
             };
 
@@ -87,23 +103,44 @@ pub fn get_predicate_expression(expr: &models::Expression, alias: &str) -> Expr
                 models::BinaryComparisonOperator::Other { name } => {
                     // todo improve code
                     if name == &("like".to_string()) {
-                        return get_sql_like_expr(left, right, false);
+                        return Ok(get_sql_like_expr(left, right, false));
                     }
                     if name == &("nlike".to_string()) {
-                        return get_sql_like_expr(left, right, true);
+                        return Ok(get_sql_like_expr(left, right, true));
+                    }
+                    if name == &("ilike".to_string()) {
+                        return Ok(get_sql_ilike_expr(left, right, false));
+                    }
+                    if name == &("nilike".to_string()) {
+                        return Ok(get_sql_ilike_expr(left, right, true));
+                    }
+                    match name.as_str() {
+                        "gt" => BinaryOperator::Gt,
+                        "gte" => BinaryOperator::GtEq,
+                        "lt" => BinaryOperator::Lt,
+                        "lte" => BinaryOperator::LtEq,
+                        _ => {
+                            return Err(ServerError::BadRequest(format!(
+                                "unsupported comparison operator {}",
+                                name
+                            )))
+                        }
                     }
-                    todo!("Only equality is supported");
                 }
             };
 
-            Expr::BinaryOp {
+            Ok(Expr::BinaryOp {
                 left: Box::new(left),
                 op: operator,
                 right: Box::new(right),
-            }
+            })
         }
 
-        models::Expression::BinaryArrayComparisonOperator { .. } => todo!(),
-        models::Expression::Exists { .. } => todo!(),
+        models::Expression::BinaryArrayComparisonOperator { .. } => Err(ServerError::BadRequest(
+            "array comparison operators are not supported".into(),
+        )),
+        models::Expression::Exists { .. } => Err(ServerError::BadRequest(
+            "exists expressions are not supported".into(),
+        )),
     }
 }
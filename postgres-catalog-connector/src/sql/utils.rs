@@ -1,6 +1,6 @@
 use sqlparser::ast::{
-    BinaryOperator, Expr, Function, FunctionArg, FunctionArgExpr, Ident, ObjectName, Offset, Query,
-    Select, SelectItem, SetExpr, TableWithJoins, Value,
+    BinaryOperator, Expr, Function, FunctionArg, FunctionArgExpr, Ident, ObjectName, Offset,
+    OrderByExpr, Query, Select, SelectItem, SetExpr, TableWithJoins, Value,
 };
 
 // Gets a quoted identifier to add in the SQL query
@@ -37,6 +37,33 @@ pub fn get_sql_function_expression(name: &str, args: Vec<Expr>, distinct: Option
     })
 }
 
+// Builds sql aggregate function expression with an ORDER BY inside the call,
+// e.g. json_agg(x ORDER BY y)
+pub fn get_sql_ordered_function_expression(
+    name: &str,
+    args: Vec<Expr>,
+    order_by: Vec<Expr>,
+) -> Expr {
+    Expr::Function(Function {
+        name: ObjectName(vec![Ident::new(name)]),
+        args: args
+            .into_iter()
+            .map(|arg| FunctionArg::Unnamed(FunctionArgExpr::Expr(arg)))
+            .collect(),
+        over: None,
+        distinct: false,
+        special: false,
+        order_by: order_by
+            .into_iter()
+            .map(|expr| OrderByExpr {
+                expr,
+                asc: None,
+                nulls_first: None,
+            })
+            .collect(),
+    })
+}
+
 // Eq operator expression to be used in the predicate
 pub fn get_sql_eq_expression(left: Expr, right: Expr) -> Expr {
     Expr::BinaryOp {
@@ -71,6 +98,15 @@ pub fn get_sql_like_expr(left: Expr, right: Expr, negated: bool) -> Expr {
         escape_char: None,
     }
 }
+// ILIKE operator expression to be used in the predicate
+pub fn get_sql_ilike_expr(left: Expr, right: Expr, negated: bool) -> Expr {
+    Expr::ILike {
+        negated,
+        expr: Box::new(left),
+        pattern: Box::new(right),
+        escape_char: None,
+    }
+}
 
 // util function to build an SQL query from constructed parameters
 pub fn get_sql_query(
@@ -18,11 +18,104 @@ pub enum SupportedTable {
     Tables,
     Columns,
     ForeignKeys,
+    TableStats,
+    ColumnStats,
+    Sequences,
+    Extensions,
+    ForeignServers,
+    UserMappings,
+    ForeignTables,
+    Roles,
+    TablePrivileges,
+    Policies,
+    TableInheritance,
+    Dependencies,
+    ViewColumnUsage,
+    Databases,
+    ServerInfo,
+    Settings,
+    Sessions,
+    Locks,
+    BlockingChains,
+    Publications,
+    PublicationTables,
+    Subscriptions,
+    ReplicationSlots,
+    ReplicationStatus,
+    IndexHealth,
+    LintFindings,
+    Ddl,
+    Indexes,
 }
-// the underlying table names of these tables in information_schema
+// the names these tables are exposed with
 pub const TABLES: &str = "tables";
 pub const COLUMNS: &str = "columns";
 pub const FOREIGN_KEYS: &str = "foreign_keys";
+pub const TABLE_STATS: &str = "table_stats";
+pub const COLUMN_STATS: &str = "column_stats";
+pub const SEQUENCES: &str = "sequences";
+pub const EXTENSIONS: &str = "extensions";
+pub const FOREIGN_SERVERS: &str = "foreign_servers";
+pub const USER_MAPPINGS: &str = "user_mappings";
+pub const FOREIGN_TABLES: &str = "foreign_tables";
+pub const ROLES: &str = "roles";
+pub const TABLE_PRIVILEGES: &str = "table_privileges";
+pub const POLICIES: &str = "policies";
+pub const TABLE_INHERITANCE: &str = "table_inheritance";
+pub const DEPENDENCIES: &str = "dependencies";
+pub const VIEW_COLUMN_USAGE: &str = "view_column_usage";
+pub const DATABASES: &str = "databases";
+pub const SERVER_INFO: &str = "server_info";
+pub const SETTINGS: &str = "settings";
+pub const SESSIONS: &str = "sessions";
+pub const LOCKS: &str = "locks";
+pub const BLOCKING_CHAINS: &str = "blocking_chains";
+pub const PUBLICATIONS: &str = "publications";
+pub const PUBLICATION_TABLES: &str = "publication_tables";
+pub const SUBSCRIPTIONS: &str = "subscriptions";
+pub const REPLICATION_SLOTS: &str = "replication_slots";
+pub const REPLICATION_STATUS: &str = "replication_status";
+pub const INDEX_HEALTH: &str = "index_health";
+pub const LINT_FINDINGS: &str = "lint_findings";
+pub const DDL: &str = "ddl";
+pub const INDEXES: &str = "indexes";
+// the names of every table supported by this data connector
+pub const SUPPORTED_TABLES: [&str; 31] = [
+    TABLES,
+    COLUMNS,
+    FOREIGN_KEYS,
+    TABLE_STATS,
+    COLUMN_STATS,
+    SEQUENCES,
+    EXTENSIONS,
+    FOREIGN_SERVERS,
+    USER_MAPPINGS,
+    FOREIGN_TABLES,
+    ROLES,
+    TABLE_PRIVILEGES,
+    POLICIES,
+    TABLE_INHERITANCE,
+    DEPENDENCIES,
+    VIEW_COLUMN_USAGE,
+    DATABASES,
+    SERVER_INFO,
+    SETTINGS,
+    SESSIONS,
+    LOCKS,
+    BLOCKING_CHAINS,
+    PUBLICATIONS,
+    PUBLICATION_TABLES,
+    SUBSCRIPTIONS,
+    REPLICATION_SLOTS,
+    REPLICATION_STATUS,
+    INDEX_HEALTH,
+    LINT_FINDINGS,
+    DDL,
+    INDEXES,
+];
+// a column every table can select, holding the name of the database the row was read from
+pub const DATABASE_NAME_COLUMN: &str = "database_name";
+
 impl SupportedTable {
     // gets the name of the underlying table from enum
     pub fn get_table_name(&self) -> String {
@@ -30,6 +123,34 @@ impl SupportedTable {
             SupportedTable::Tables => TABLES.to_string(),
             SupportedTable::Columns => COLUMNS.to_string(),
             SupportedTable::ForeignKeys => FOREIGN_KEYS.to_string(),
+            SupportedTable::TableStats => TABLE_STATS.to_string(),
+            SupportedTable::ColumnStats => COLUMN_STATS.to_string(),
+            SupportedTable::Sequences => SEQUENCES.to_string(),
+            SupportedTable::Extensions => EXTENSIONS.to_string(),
+            SupportedTable::ForeignServers => FOREIGN_SERVERS.to_string(),
+            SupportedTable::UserMappings => USER_MAPPINGS.to_string(),
+            SupportedTable::ForeignTables => FOREIGN_TABLES.to_string(),
+            SupportedTable::Roles => ROLES.to_string(),
+            SupportedTable::TablePrivileges => TABLE_PRIVILEGES.to_string(),
+            SupportedTable::Policies => POLICIES.to_string(),
+            SupportedTable::TableInheritance => TABLE_INHERITANCE.to_string(),
+            SupportedTable::Dependencies => DEPENDENCIES.to_string(),
+            SupportedTable::ViewColumnUsage => VIEW_COLUMN_USAGE.to_string(),
+            SupportedTable::Databases => DATABASES.to_string(),
+            SupportedTable::ServerInfo => SERVER_INFO.to_string(),
+            SupportedTable::Settings => SETTINGS.to_string(),
+            SupportedTable::Sessions => SESSIONS.to_string(),
+            SupportedTable::Locks => LOCKS.to_string(),
+            SupportedTable::BlockingChains => BLOCKING_CHAINS.to_string(),
+            SupportedTable::Publications => PUBLICATIONS.to_string(),
+            SupportedTable::PublicationTables => PUBLICATION_TABLES.to_string(),
+            SupportedTable::Subscriptions => SUBSCRIPTIONS.to_string(),
+            SupportedTable::ReplicationSlots => REPLICATION_SLOTS.to_string(),
+            SupportedTable::ReplicationStatus => REPLICATION_STATUS.to_string(),
+            SupportedTable::IndexHealth => INDEX_HEALTH.to_string(),
+            SupportedTable::LintFindings => LINT_FINDINGS.to_string(),
+            SupportedTable::Ddl => DDL.to_string(),
+            SupportedTable::Indexes => INDEXES.to_string(),
         }
     }
 
@@ -38,6 +159,91 @@ impl SupportedTable {
         "information_schema".to_string()
     }
 
+    // gets the column that holds the Postgres schema of each row, which is used to leave out
+    // the rows of the system schemas. None for tables whose rows do not belong to a schema.
+    pub fn get_schema_column(&self) -> Option<&'static str> {
+        match self {
+            SupportedTable::Tables
+            | SupportedTable::Columns
+            | SupportedTable::TableStats
+            | SupportedTable::ColumnStats
+            | SupportedTable::ForeignTables
+            | SupportedTable::TablePrivileges
+            | SupportedTable::Policies
+            | SupportedTable::TableInheritance
+            | SupportedTable::PublicationTables
+            | SupportedTable::IndexHealth
+            | SupportedTable::LintFindings
+            | SupportedTable::Ddl
+            | SupportedTable::Indexes => Some("table_schema"),
+            SupportedTable::ForeignKeys => Some("schema_from"),
+            SupportedTable::Sequences => Some("sequence_schema"),
+            SupportedTable::ViewColumnUsage => Some("view_schema"),
+            SupportedTable::Extensions
+            | SupportedTable::ForeignServers
+            | SupportedTable::UserMappings
+            | SupportedTable::Roles
+            | SupportedTable::Dependencies
+            | SupportedTable::Databases
+            | SupportedTable::ServerInfo
+            | SupportedTable::Settings
+            | SupportedTable::Sessions
+            | SupportedTable::Locks
+            | SupportedTable::BlockingChains
+            | SupportedTable::Publications
+            | SupportedTable::Subscriptions
+            | SupportedTable::ReplicationSlots
+            | SupportedTable::ReplicationStatus => None,
+        }
+    }
+
+    // whether the rows of the table are shared by every database on the server, in which case
+    // a query against all databases would return each of them once per database
+    pub fn is_server_wide(&self) -> bool {
+        match self {
+            SupportedTable::Roles
+            | SupportedTable::Databases
+            | SupportedTable::ServerInfo
+            | SupportedTable::Settings
+            | SupportedTable::Sessions
+            | SupportedTable::Locks
+            | SupportedTable::BlockingChains
+            | SupportedTable::Subscriptions
+            | SupportedTable::ReplicationSlots
+            | SupportedTable::ReplicationStatus => true,
+            SupportedTable::Tables
+            | SupportedTable::Columns
+            | SupportedTable::ForeignKeys
+            | SupportedTable::TableStats
+            | SupportedTable::ColumnStats
+            | SupportedTable::Sequences
+            | SupportedTable::Extensions
+            | SupportedTable::ForeignServers
+            | SupportedTable::UserMappings
+            | SupportedTable::ForeignTables
+            | SupportedTable::TablePrivileges
+            | SupportedTable::Policies
+            | SupportedTable::TableInheritance
+            | SupportedTable::Dependencies
+            | SupportedTable::ViewColumnUsage
+            | SupportedTable::Publications
+            | SupportedTable::PublicationTables
+            | SupportedTable::IndexHealth
+            | SupportedTable::LintFindings
+            | SupportedTable::Ddl
+            | SupportedTable::Indexes => false,
+        }
+    }
+
+    // whether the rows can be ordered by the column. JSON columns are arrays or objects, which
+    // Postgres has no ordering operator for.
+    pub fn is_orderable_column(&self, column: &str) -> bool {
+        !self
+            .get_columns()
+            .iter()
+            .any(|c| c.name == column && c.r#type == "JSON")
+    }
+
     // gets the columns of the underlying table from enum
     pub fn get_columns(&self) -> Vec<ColumnInfo> {
         match self {
@@ -51,6 +257,66 @@ impl SupportedTable {
                         r#type: "String".into(),
                         name: "table_schema".into(),
                     },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_foreign".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "owner".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "rls_enabled".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "rls_forced".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_partitioned".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_partition".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "partition_parent_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "partition_parent_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "partition_bound".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "partition_key".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "partition_strategy".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "partition_depth".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "comment".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_published".into(),
+                    },
                 ]
             }
             SupportedTable::Columns => {
@@ -71,6 +337,54 @@ impl SupportedTable {
                         r#type: "String".into(),
                         name: "data_type".into(),
                     },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_nullable".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "column_default".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "ordinal_position".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "character_maximum_length".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "numeric_precision".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "numeric_scale".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_identity".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "identity_generation".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_generated".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "generation_expression".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "collation_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "comment".into(),
+                    },
                 ]
             }
             SupportedTable::ForeignKeys => {
@@ -84,7 +398,7 @@ impl SupportedTable {
                         name: "table_from".into(),
                     },
                     ColumnInfo {
-                        r#type: "String".into(),
+                        r#type: "JSON".into(),
                         name: "column_mapping".into(),
                     },
                     ColumnInfo {
@@ -109,6 +423,1194 @@ impl SupportedTable {
                     },
                 ]
             }
+            SupportedTable::TableStats => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "row_estimate".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "total_size".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "table_size".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "indexes_size".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "toast_size".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "live_tuples".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "dead_tuples".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "last_vacuum".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "last_autovacuum".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "last_analyze".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "last_autoanalyze".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "seq_scan".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "idx_scan".into(),
+                    },
+                ]
+            }
+            SupportedTable::ColumnStats => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "column_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "inherited".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "null_frac".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "n_distinct".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "avg_width".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "most_common_vals".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "most_common_freqs".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "histogram_bounds".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "correlation".into(),
+                    },
+                ]
+            }
+            SupportedTable::Sequences => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "sequence_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "sequence_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "data_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "start_value".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "increment_by".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "min_value".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "max_value".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "cycle".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "last_value".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "column_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "percent_used".into(),
+                    },
+                ]
+            }
+            SupportedTable::Extensions => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "extension_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "installed".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "installed_version".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "default_version".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "extension_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "relocatable".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "comment".into(),
+                    },
+                ]
+            }
+            SupportedTable::ForeignServers => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "server_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "foreign_data_wrapper".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "server_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "server_version".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "owner".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "options".into(),
+                    },
+                ]
+            }
+            SupportedTable::UserMappings => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "server_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "user_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "option_names".into(),
+                    },
+                ]
+            }
+            SupportedTable::ForeignTables => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "server_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "options".into(),
+                    },
+                ]
+            }
+            SupportedTable::Roles => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "role_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_superuser".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "can_login".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "inherits".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "can_create_role".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "can_create_db".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_replication".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "bypass_rls".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "connection_limit".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "valid_until".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "member_of".into(),
+                    },
+                ]
+            }
+            SupportedTable::TablePrivileges => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "column_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "grantor".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "grantee".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "privilege_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_grantable".into(),
+                    },
+                ]
+            }
+            SupportedTable::Policies => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "policy_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_permissive".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "command".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "roles".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "using_expression".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "with_check_expression".into(),
+                    },
+                ]
+            }
+            SupportedTable::TableInheritance => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "parent_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "parent_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "position".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_partition".into(),
+                    },
+                ]
+            }
+            SupportedTable::Dependencies => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "referencing_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "referencing_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "referencing_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "referencing_column".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "referencing_identity".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "referenced_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "referenced_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "referenced_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "referenced_column".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "referenced_identity".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "dependency_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "depth".into(),
+                    },
+                ]
+            }
+            SupportedTable::ViewColumnUsage => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "view_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "view_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "view_column_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_materialized".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "column_name".into(),
+                    },
+                ]
+            }
+            SupportedTable::Databases => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "database_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "owner".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "encoding".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "collation".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "ctype".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "size_bytes".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "tablespace".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_template".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "allow_connections".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "connection_limit".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_current".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "comment".into(),
+                    },
+                ]
+            }
+            SupportedTable::ServerInfo => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "server_version".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "server_version_num".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "version".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "current_database".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "started_at".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "config_loaded_at".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_in_recovery".into(),
+                    },
+                ]
+            }
+            SupportedTable::Settings => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "setting".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "unit".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "category".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "description".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "context".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "value_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "source".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "default_value".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "reset_value".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "differs_from_default".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "pending_restart".into(),
+                    },
+                ]
+            }
+            SupportedTable::Sessions => {
+                vec![
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "pid".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "database".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "username".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "application_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "client_address".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "backend_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "state".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "wait_event_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "wait_event".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "backend_start".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "transaction_start".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "query_start".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "state_change".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "transaction_duration_seconds".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "query_duration_seconds".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "query".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "blocked_by".into(),
+                    },
+                ]
+            }
+            SupportedTable::Locks => {
+                vec![
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "pid".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "lock_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "mode".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "granted".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "fastpath".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "database".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "page".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "tuple".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "virtual_transaction_id".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "transaction_id".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "holder_virtual_transaction_id".into(),
+                    },
+                ]
+            }
+            SupportedTable::BlockingChains => {
+                vec![
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "blocked_pid".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "blocking_pid".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "depth".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "chain".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_root_blocker".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "blocked_username".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "blocked_wait_event_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "blocked_duration_seconds".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "blocked_query".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "blocking_username".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "blocking_state".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "blocking_transaction_duration_seconds".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "blocking_query".into(),
+                    },
+                ]
+            }
+            SupportedTable::Publications => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "publication_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "owner".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "all_tables".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "publishes_insert".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "publishes_update".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "publishes_delete".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "publishes_truncate".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "via_partition_root".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "table_count".into(),
+                    },
+                ]
+            }
+            SupportedTable::PublicationTables => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "publication_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                ]
+            }
+            SupportedTable::Subscriptions => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "subscription_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "database".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "owner".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "enabled".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "slot_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "publications".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "worker_pid".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "received_lsn".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "latest_end_lsn".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "last_message_received_at".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "latest_end_at".into(),
+                    },
+                ]
+            }
+            SupportedTable::ReplicationSlots => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "slot_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "slot_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "plugin".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "database".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "temporary".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "active".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "active_pid".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "restart_lsn".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "confirmed_flush_lsn".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "wal_status".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "retained_bytes".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "lag_bytes".into(),
+                    },
+                ]
+            }
+            SupportedTable::ReplicationStatus => {
+                vec![
+                    ColumnInfo {
+                        r#type: "Int".into(),
+                        name: "pid".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "username".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "application_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "client_address".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "backend_start".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "state".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "sync_state".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "sent_lsn".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "write_lsn".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "flush_lsn".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "replay_lsn".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "replay_lag_bytes".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "write_lag_seconds".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "flush_lag_seconds".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Float".into(),
+                        name: "replay_lag_seconds".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Timestamp".into(),
+                        name: "last_reply_at".into(),
+                    },
+                ]
+            }
+            SupportedTable::IndexHealth => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "index_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "constraint_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "finding".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "severity".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "details".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "related_index_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "index_size".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "idx_scan".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "BigInt".into(),
+                        name: "idx_tup_read".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "suggested_sql".into(),
+                    },
+                ]
+            }
+            SupportedTable::LintFindings => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "rule_id".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "severity".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "object_type".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "object_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "message".into(),
+                    },
+                ]
+            }
+            SupportedTable::Ddl => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "ddl".into(),
+                    },
+                ]
+            }
+            SupportedTable::Indexes => {
+                vec![
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_schema".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "table_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "index_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "access_method".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_primary".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_unique".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "Boolean".into(),
+                        name: "is_valid".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "constraint_name".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "JSON".into(),
+                        name: "key_columns".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "predicate".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "definition".into(),
+                    },
+                    ColumnInfo {
+                        r#type: "String".into(),
+                        name: "comment".into(),
+                    },
+                ]
+            }
         }
     }
 
@@ -127,6 +1629,34 @@ impl ToString for SupportedTable {
             SupportedTable::Tables => TABLES.into(),
             SupportedTable::Columns => COLUMNS.into(),
             SupportedTable::ForeignKeys => FOREIGN_KEYS.into(),
+            SupportedTable::TableStats => TABLE_STATS.into(),
+            SupportedTable::ColumnStats => COLUMN_STATS.into(),
+            SupportedTable::Sequences => SEQUENCES.into(),
+            SupportedTable::Extensions => EXTENSIONS.into(),
+            SupportedTable::ForeignServers => FOREIGN_SERVERS.into(),
+            SupportedTable::UserMappings => USER_MAPPINGS.into(),
+            SupportedTable::ForeignTables => FOREIGN_TABLES.into(),
+            SupportedTable::Roles => ROLES.into(),
+            SupportedTable::TablePrivileges => TABLE_PRIVILEGES.into(),
+            SupportedTable::Policies => POLICIES.into(),
+            SupportedTable::TableInheritance => TABLE_INHERITANCE.into(),
+            SupportedTable::Dependencies => DEPENDENCIES.into(),
+            SupportedTable::ViewColumnUsage => VIEW_COLUMN_USAGE.into(),
+            SupportedTable::Databases => DATABASES.into(),
+            SupportedTable::ServerInfo => SERVER_INFO.into(),
+            SupportedTable::Settings => SETTINGS.into(),
+            SupportedTable::Sessions => SESSIONS.into(),
+            SupportedTable::Locks => LOCKS.into(),
+            SupportedTable::BlockingChains => BLOCKING_CHAINS.into(),
+            SupportedTable::Publications => PUBLICATIONS.into(),
+            SupportedTable::PublicationTables => PUBLICATION_TABLES.into(),
+            SupportedTable::Subscriptions => SUBSCRIPTIONS.into(),
+            SupportedTable::ReplicationSlots => REPLICATION_SLOTS.into(),
+            SupportedTable::ReplicationStatus => REPLICATION_STATUS.into(),
+            SupportedTable::IndexHealth => INDEX_HEALTH.into(),
+            SupportedTable::LintFindings => LINT_FINDINGS.into(),
+            SupportedTable::Ddl => DDL.into(),
+            SupportedTable::Indexes => INDEXES.into(),
         }
     }
 }
@@ -141,6 +1671,34 @@ impl FromStr for SupportedTable {
             TABLES => Ok(SupportedTable::Tables),
             COLUMNS => Ok(SupportedTable::Columns),
             FOREIGN_KEYS => Ok(SupportedTable::ForeignKeys),
+            TABLE_STATS => Ok(SupportedTable::TableStats),
+            COLUMN_STATS => Ok(SupportedTable::ColumnStats),
+            SEQUENCES => Ok(SupportedTable::Sequences),
+            EXTENSIONS => Ok(SupportedTable::Extensions),
+            FOREIGN_SERVERS => Ok(SupportedTable::ForeignServers),
+            USER_MAPPINGS => Ok(SupportedTable::UserMappings),
+            FOREIGN_TABLES => Ok(SupportedTable::ForeignTables),
+            ROLES => Ok(SupportedTable::Roles),
+            TABLE_PRIVILEGES => Ok(SupportedTable::TablePrivileges),
+            POLICIES => Ok(SupportedTable::Policies),
+            TABLE_INHERITANCE => Ok(SupportedTable::TableInheritance),
+            DEPENDENCIES => Ok(SupportedTable::Dependencies),
+            VIEW_COLUMN_USAGE => Ok(SupportedTable::ViewColumnUsage),
+            DATABASES => Ok(SupportedTable::Databases),
+            SERVER_INFO => Ok(SupportedTable::ServerInfo),
+            SETTINGS => Ok(SupportedTable::Settings),
+            SESSIONS => Ok(SupportedTable::Sessions),
+            LOCKS => Ok(SupportedTable::Locks),
+            BLOCKING_CHAINS => Ok(SupportedTable::BlockingChains),
+            PUBLICATIONS => Ok(SupportedTable::Publications),
+            PUBLICATION_TABLES => Ok(SupportedTable::PublicationTables),
+            SUBSCRIPTIONS => Ok(SupportedTable::Subscriptions),
+            REPLICATION_SLOTS => Ok(SupportedTable::ReplicationSlots),
+            REPLICATION_STATUS => Ok(SupportedTable::ReplicationStatus),
+            INDEX_HEALTH => Ok(SupportedTable::IndexHealth),
+            LINT_FINDINGS => Ok(SupportedTable::LintFindings),
+            DDL => Ok(SupportedTable::Ddl),
+            INDEXES => Ok(SupportedTable::Indexes),
             _ => Err(ParseSupportedTableErr),
         }
     }